@@ -39,6 +39,10 @@ call /credit/52542282760ee313280017f9 credit-details
 call /genre/movie/list genre-movie-list
 call /genre/tv/list genre-tv-list
 
+# lists
+call /list/1 list-details
+call /list/1/item_status list-item-status movie_id=787699
+
 # movies
 call /movie/550 movie-details
 call /movie/550/alternative_titles movie-alternative-titles
@@ -63,16 +67,59 @@ call /movie/upcoming movie-upcoming
 
 # person
 call /person/287 person-details
+call /person/287/combined_credits person-combined-credits
+call /person/287/external_ids person-external-ids
+call /person/287/images person-images
+call /person/287/movie_credits person-movie-credits
+call /person/287/tagged_images person-tagged-images
+call /person/287/translations person-translations
+call /person/287/tv_credits person-tv-credits
+call /person/latest person-latest
+call /person/popular person-popular
 
 # search
+call /search/collection search-collection query=star+wars
+call /search/company search-company query=pixar
+call /search/keyword search-keyword query=superhero
 call /search/movie search-movie query=RRRrrrr
+call /search/person search-person query=brad+pitt
 call /search/tv search-tv query=game+of+thrones
 
+# trending
+call /trending/all/day trending-all
+call /trending/person/day trending-person
+
 # tvshows
 call /tv/1399 tv-details
+call /tv/1399/alternative_titles tv-alternative-titles
+call /tv/1399/changes tv-changes
+call /tv/1399/external_ids tv-external-ids
+call /tv/1399/lists tv-lists
+call /tv/1399/reviews tv-reviews
+call /tv/1399/screened_theatrically tv-screened-theatrically
+call /tv/1399/aggregate_credits tv-aggregate-credits
+call /tv/airing_today tv-airing-today
+call /tv/on_the_air tv-on-the-air
+call /tv/top_rated tv-top-rated
+call /tv/1399/recommendations tv-recommendations
 call /tv/1399/similar tv-similar
+call /tv/63926/episode_groups tv-episode-groups
+call /tv/episode_group/5c1fbd8a0e0a26191b6e7a00 tv-episode-group-details
 call /tv/1399/season/1 tv-season-details
+call /tv/1399/season/1/aggregate_credits tv-season-aggregate-credits
+call /tv/1399/season/1/credits tv-season-credits
+call /tv/1399/season/1/external_ids tv-season-external-ids
+call /tv/1399/season/1/images tv-season-images
+call /tv/1399/season/1/translations tv-season-translations
+call /tv/1399/season/1/videos tv-season-videos
+call /tv/1399/season/1/watch/providers tv-season-watch-providers
+call /tv/season/3624/changes tv-season-changes
 call /tv/1399/season/1/episode/1 tv-episode-details
+call /tv/1399/season/1/episode/1/credits tv-episode-credits
+call /tv/1399/season/1/episode/1/external_ids tv-episode-external-ids
+call /tv/1399/season/1/episode/1/images tv-episode-images
+call /tv/1399/season/1/episode/1/translations tv-episode-translations
+call /tv/1399/season/1/episode/1/videos tv-episode-videos
 
 # watch providers
 call /watch/providers/movie watch-provider-movie-list
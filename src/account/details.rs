@@ -0,0 +1,165 @@
+use std::borrow::Cow;
+
+use crate::authentication::Session;
+
+/// Get the details of the account owning the session.
+///
+/// ```rust
+/// use tmdb_api::prelude::Command;
+/// use tmdb_api::authentication::Session;
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::account::details::AccountDetails;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into())
+///         .with_session(Some(Session::User("session-id".into())));
+///     let result = AccountDetails::default().execute(&client).await;
+///     match result {
+///         Ok(res) => println!("found: {:#?}", res),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct AccountDetails {
+    /// ID of the account, optional as the session is enough to find it.
+    pub account_id: Option<u64>,
+    /// Session to use instead of the one of the client.
+    pub session: Option<Session>,
+}
+
+impl AccountDetails {
+    pub fn with_account_id(mut self, value: Option<u64>) -> Self {
+        self.account_id = value;
+        self
+    }
+
+    pub fn with_session(mut self, value: Option<Session>) -> Self {
+        self.session = value;
+        self
+    }
+}
+
+impl crate::prelude::Command for AccountDetails {
+    type Output = super::Account;
+
+    fn needs_session(&self) -> bool {
+        true
+    }
+
+    fn path(&self) -> Cow<'static, str> {
+        match self.account_id {
+            Some(account_id) => Cow::Owned(format!("/account/{account_id}")),
+            None => Cow::Borrowed("/account"),
+        }
+    }
+
+    fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
+        let mut res = Vec::new();
+        if let Some(ref session) = self.session {
+            let (name, value) = session.as_param();
+            res.push((name, Cow::Borrowed(value)));
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+
+    use crate::authentication::Session;
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::prelude::Command;
+
+    use super::AccountDetails;
+
+    #[tokio::test]
+    async fn it_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/account")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("api_key".into(), "secret".into()),
+                Matcher::UrlEncoded("session_id".into(), "session".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/account-details.json"))
+            .create_async()
+            .await;
+
+        let result = AccountDetails::default()
+            .with_session(Some(Session::User("session".into())))
+            .execute(&client)
+            .await
+            .unwrap();
+        assert_eq!(result.id, 548);
+        assert_eq!(result.username, "travisbell");
+    }
+
+    #[tokio::test]
+    async fn with_account_id() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .with_session(Some(Session::User("session".into())))
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/account/548")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("api_key".into(), "secret".into()),
+                Matcher::UrlEncoded("session_id".into(), "session".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/account-details.json"))
+            .create_async()
+            .await;
+
+        let result = AccountDetails::default()
+            .with_account_id(Some(548))
+            .execute(&client)
+            .await
+            .unwrap();
+        assert_eq!(result.id, 548);
+    }
+
+    #[tokio::test]
+    async fn invalid_api_key() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/account")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(401)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/invalid-api-key.json"))
+            .create_async()
+            .await;
+
+        let err = AccountDetails::default()
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 7);
+    }
+}
@@ -0,0 +1,163 @@
+use std::borrow::Cow;
+
+use crate::authentication::Session;
+use crate::client::Method;
+use crate::common::{MediaType, StatusResult};
+
+/// Mark a movie or a tv show as favorite, or remove it from the favorites.
+///
+/// ```rust
+/// use tmdb_api::prelude::Command;
+/// use tmdb_api::authentication::Session;
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::common::MediaType;
+/// use tmdb_api::account::mark_favorite::MarkFavorite;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into())
+///         .with_session(Some(Session::User("session-id".into())));
+///     let cmd = MarkFavorite::new(548, MediaType::Movie, 550, true);
+///     let result = cmd.execute(&client).await;
+///     match result {
+///         Ok(res) => println!("found: {:#?}", res),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct MarkFavorite {
+    /// ID of the account.
+    pub account_id: u64,
+    /// Session to use instead of the one of the client.
+    pub session: Option<Session>,
+    pub media_type: MediaType,
+    pub media_id: u64,
+    /// `true` to add to the favorites, `false` to remove from it.
+    pub favorite: bool,
+}
+
+impl MarkFavorite {
+    pub fn new(account_id: u64, media_type: MediaType, media_id: u64, favorite: bool) -> Self {
+        Self {
+            account_id,
+            session: None,
+            media_type,
+            media_id,
+            favorite,
+        }
+    }
+
+    pub fn with_session(mut self, value: Option<Session>) -> Self {
+        self.session = value;
+        self
+    }
+}
+
+impl crate::prelude::Command for MarkFavorite {
+    type Output = StatusResult;
+
+    fn needs_session(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::Post
+    }
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Owned(format!("/account/{}/favorite", self.account_id))
+    }
+
+    fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
+        let mut res = Vec::new();
+        if let Some(ref session) = self.session {
+            let (name, value) = session.as_param();
+            res.push((name, Cow::Borrowed(value)));
+        }
+        res
+    }
+
+    fn body(&self) -> Option<serde_json::Value> {
+        Some(serde_json::json!({
+            "media_type": self.media_type,
+            "media_id": self.media_id,
+            "favorite": self.favorite,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+
+    use crate::authentication::Session;
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::common::MediaType;
+    use crate::prelude::Command;
+
+    use super::MarkFavorite;
+
+    #[tokio::test]
+    async fn it_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .with_session(Some(Session::User("session".into())))
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("POST", "/account/548/favorite")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("api_key".into(), "secret".into()),
+                Matcher::UrlEncoded("session_id".into(), "session".into()),
+            ]))
+            .match_body(Matcher::Json(serde_json::json!({
+                "media_type": "tv",
+                "media_id": 1399,
+                "favorite": true,
+            })))
+            .with_status(201)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/account-status.json"))
+            .create_async()
+            .await;
+
+        let result = MarkFavorite::new(548, MediaType::Tv, 1399, true)
+            .execute(&client)
+            .await
+            .unwrap();
+        assert!(result.success);
+        assert_eq!(result.status_code, Some(1));
+    }
+
+    #[tokio::test]
+    async fn invalid_api_key() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("POST", "/account/548/favorite")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(401)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/invalid-api-key.json"))
+            .create_async()
+            .await;
+
+        let err = MarkFavorite::new(548, MediaType::Movie, 550, false)
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 7);
+    }
+}
@@ -0,0 +1,120 @@
+#[cfg(feature = "commands")]
+pub mod details;
+#[cfg(feature = "commands")]
+pub mod favorite_movies;
+#[cfg(feature = "commands")]
+pub mod favorite_tv;
+#[cfg(feature = "commands")]
+pub mod mark_favorite;
+#[cfg(feature = "commands")]
+pub mod mark_watchlist;
+#[cfg(feature = "commands")]
+pub mod rated_episodes;
+#[cfg(feature = "commands")]
+pub mod rated_movies;
+#[cfg(feature = "commands")]
+pub mod rated_tv;
+#[cfg(feature = "commands")]
+pub mod watchlist_movies;
+#[cfg(feature = "commands")]
+pub mod watchlist_tv;
+
+use std::fmt;
+
+use crate::common::id::{EpisodeId, TvId};
+use crate::movie::MovieShort;
+use crate::tvshow::{EpisodeShort, TVShowShort};
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct Gravatar {
+    pub hash: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct TmdbAvatar {
+    pub avatar_path: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct Avatar {
+    pub gravatar: Option<Gravatar>,
+    pub tmdb: Option<TmdbAvatar>,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct Account {
+    pub id: u64,
+    pub avatar: Avatar,
+    pub iso_639_1: String,
+    pub iso_3166_1: String,
+    #[serde(deserialize_with = "crate::util::empty_string::deserialize")]
+    pub name: Option<String>,
+    pub include_adult: bool,
+    pub username: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct RatedMovie {
+    #[serde(flatten)]
+    pub inner: MovieShort,
+    pub rating: f32,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct RatedTVShow {
+    #[serde(flatten)]
+    pub inner: TVShowShort,
+    pub rating: f32,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct RatedEpisode {
+    #[serde(flatten)]
+    pub inner: EpisodeShort,
+    pub show_id: TvId,
+    pub rating: f32,
+}
+
+/// State of a movie, tv show or episode for the account owning the session.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct AccountStates {
+    pub id: u64,
+    #[serde(default)]
+    pub favorite: bool,
+    /// Rating given by the account, if any.
+    #[serde(default, with = "crate::util::rated")]
+    pub rated: Option<f32>,
+    #[serde(default)]
+    pub watchlist: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct EpisodeAccountStates {
+    pub id: EpisodeId,
+    pub episode_number: u64,
+    #[serde(default, with = "crate::util::rated")]
+    pub rated: Option<f32>,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct SeasonAccountStates {
+    pub id: u64,
+    pub results: Vec<EpisodeAccountStates>,
+}
+
+/// Order of the items in the account lists, based on when they were added.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SortBy {
+    #[default]
+    CreatedAtAsc,
+    CreatedAtDesc,
+}
+
+impl fmt::Display for SortBy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::CreatedAtAsc => write!(f, "created_at.asc"),
+            Self::CreatedAtDesc => write!(f, "created_at.desc"),
+        }
+    }
+}
@@ -0,0 +1,206 @@
+use std::borrow::Cow;
+
+use crate::authentication::Session;
+use crate::common::PaginatedResult;
+
+/// Get the list of tv episodes rated by an account.
+///
+/// The session is taken from the command when provided, from the client otherwise.
+///
+/// ```rust
+/// use tmdb_api::prelude::Command;
+/// use tmdb_api::authentication::Session;
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::account::rated_episodes::AccountRatedEpisodes;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into())
+///         .with_session(Some(Session::User("session-id".into())));
+///     let cmd = AccountRatedEpisodes::new(548);
+///     let result = cmd.execute(&client).await;
+///     match result {
+///         Ok(res) => println!("found: {:#?}", res),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct AccountRatedEpisodes {
+    /// ID of the account.
+    pub account_id: u64,
+    /// Session to use instead of the one of the client.
+    pub session: Option<Session>,
+    /// ISO 639-1 value to display translated data for the fields that support it.
+    pub language: Option<String>,
+    /// Which page to query.
+    pub page: Option<u32>,
+    pub sort_by: Option<super::SortBy>,
+}
+
+impl AccountRatedEpisodes {
+    pub fn new(account_id: u64) -> Self {
+        Self {
+            account_id,
+            session: None,
+            language: None,
+            page: None,
+            sort_by: None,
+        }
+    }
+
+    pub fn with_session(mut self, value: Option<Session>) -> Self {
+        self.session = value;
+        self
+    }
+
+    pub fn with_language(mut self, value: Option<String>) -> Self {
+        self.language = value;
+        self
+    }
+
+    pub fn with_page(mut self, value: Option<u32>) -> Self {
+        self.page = value;
+        self
+    }
+
+    pub fn with_sort_by(mut self, value: Option<super::SortBy>) -> Self {
+        self.sort_by = value;
+        self
+    }
+}
+
+impl crate::prelude::Command for AccountRatedEpisodes {
+    type Output = PaginatedResult<super::RatedEpisode>;
+
+    fn needs_session(&self) -> bool {
+        true
+    }
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Owned(format!("/account/{}/rated/tv/episodes", self.account_id))
+    }
+
+    fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
+        let mut res = Vec::new();
+        if let Some(ref session) = self.session {
+            let (name, value) = session.as_param();
+            res.push((name, Cow::Borrowed(value)));
+        }
+        if let Some(ref language) = self.language {
+            res.push(("language", Cow::Borrowed(language.as_str())));
+        }
+        if let Some(page) = self.page {
+            res.push(("page", Cow::Owned(page.to_string())));
+        }
+        if let Some(sort_by) = self.sort_by {
+            res.push(("sort_by", Cow::Owned(sort_by.to_string())));
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+
+    use crate::account::SortBy;
+    use crate::authentication::Session;
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::prelude::Command;
+
+    use super::AccountRatedEpisodes;
+
+    #[tokio::test]
+    async fn it_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .with_session(Some(Session::User("client-session".into())))
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/account/548/rated/tv/episodes")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("api_key".into(), "secret".into()),
+                Matcher::UrlEncoded("session_id".into(), "client-session".into()),
+                Matcher::UrlEncoded("sort_by".into(), "created_at.desc".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/account-rated-episodes.json"))
+            .create_async()
+            .await;
+
+        let result = AccountRatedEpisodes::new(548)
+            .with_sort_by(Some(SortBy::CreatedAtDesc))
+            .execute(&client)
+            .await
+            .unwrap();
+        assert_eq!(result.page, 1);
+        assert_eq!(result.results.first().unwrap().inner.id, 63056);
+        let item = result.results.first().unwrap();
+        assert_eq!(item.show_id, 1399);
+        assert_eq!(item.rating, 10.0);
+    }
+
+    #[tokio::test]
+    async fn should_use_command_session() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .with_session(Some(Session::User("client-session".into())))
+            .build()
+            .unwrap();
+
+        let m = server
+            .mock("GET", "/account/548/rated/tv/episodes")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("api_key".into(), "secret".into()),
+                Matcher::UrlEncoded("session_id".into(), "command-session".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/account-rated-episodes.json"))
+            .create_async()
+            .await;
+
+        AccountRatedEpisodes::new(548)
+            .with_session(Some(Session::User("command-session".into())))
+            .execute(&client)
+            .await
+            .unwrap();
+        m.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn invalid_api_key() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/account/548/rated/tv/episodes")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(401)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/invalid-api-key.json"))
+            .create_async()
+            .await;
+
+        let err = AccountRatedEpisodes::new(548)
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 7);
+    }
+}
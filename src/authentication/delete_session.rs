@@ -0,0 +1,122 @@
+use std::borrow::Cow;
+
+use crate::client::Method;
+use crate::common::StatusResult;
+
+/// Command to log out of a session.
+///
+/// ```rust
+/// use tmdb_api::prelude::Command;
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::authentication::delete_session::DeleteSession;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into());
+///     let cmd = DeleteSession::new("session-id".into());
+///     let result = cmd.execute(&client).await;
+///     match result {
+///         Ok(res) => println!("found: {:#?}", res),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct DeleteSession {
+    /// ID of the session to delete.
+    pub session_id: String,
+}
+
+impl DeleteSession {
+    pub fn new(session_id: String) -> Self {
+        Self { session_id }
+    }
+}
+
+impl crate::prelude::Command for DeleteSession {
+    type Output = StatusResult;
+
+    fn method(&self) -> Method {
+        Method::Delete
+    }
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/authentication/session")
+    }
+
+    fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
+        Vec::new()
+    }
+
+    fn body(&self) -> Option<serde_json::Value> {
+        Some(serde_json::json!({ "session_id": self.session_id }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::prelude::Command;
+
+    use super::DeleteSession;
+
+    #[tokio::test]
+    async fn it_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("DELETE", "/authentication/session")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .match_body(Matcher::Json(serde_json::json!({
+                "session_id": "79191836ddaa0da3df76a5ffef6f07ad6ab0c641",
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!(
+                "../../assets/authentication-delete-session.json"
+            ))
+            .create_async()
+            .await;
+
+        let result = DeleteSession::new("79191836ddaa0da3df76a5ffef6f07ad6ab0c641".into())
+            .execute(&client)
+            .await
+            .unwrap();
+        assert!(result.success);
+    }
+
+    #[tokio::test]
+    async fn resource_not_found() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("DELETE", "/authentication/session")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/resource-not-found.json"))
+            .create_async()
+            .await;
+
+        let err = DeleteSession::new("whatever".into())
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 34);
+    }
+}
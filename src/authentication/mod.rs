@@ -0,0 +1,88 @@
+#[cfg(feature = "commands")]
+pub mod delete_session;
+#[cfg(feature = "commands")]
+pub mod new_guest_session;
+#[cfg(feature = "commands")]
+pub mod new_request_token;
+#[cfg(feature = "commands")]
+pub mod new_session;
+#[cfg(feature = "commands")]
+pub mod validate_request_token;
+
+#[cfg(feature = "commands")]
+const SESSION_ID: &str = "session_id";
+#[cfg(feature = "commands")]
+const GUEST_SESSION_ID: &str = "guest_session_id";
+
+/// Session used to act on behalf of a user.
+///
+/// When set on the client, it's attached as `session_id` or `guest_session_id` to the
+/// commands acting on behalf of a user, unless the command already provides one.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Session {
+    /// Session created from an approved request token.
+    User(String),
+    /// Guest session, only allowed to rate movies and tv shows.
+    Guest(String),
+}
+
+impl Session {
+    pub fn id(&self) -> &str {
+        match self {
+            Self::User(inner) => inner.as_str(),
+            Self::Guest(inner) => inner.as_str(),
+        }
+    }
+
+    pub fn is_guest(&self) -> bool {
+        matches!(self, Self::Guest(_))
+    }
+
+    #[cfg(feature = "commands")]
+    pub(crate) fn as_param(&self) -> (&'static str, &str) {
+        match self {
+            Self::User(inner) => (SESSION_ID, inner.as_str()),
+            Self::Guest(inner) => (GUEST_SESSION_ID, inner.as_str()),
+        }
+    }
+
+    #[cfg(feature = "commands")]
+    pub(crate) fn is_param(name: &str) -> bool {
+        name == SESSION_ID || name == GUEST_SESSION_ID
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct RequestToken {
+    pub success: bool,
+    #[serde(with = "crate::util::date_time_utc")]
+    pub expires_at: chrono::DateTime<chrono::Utc>,
+    pub request_token: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct SessionResult {
+    pub success: bool,
+    pub session_id: String,
+}
+
+impl From<SessionResult> for Session {
+    fn from(value: SessionResult) -> Self {
+        Self::User(value.session_id)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GuestSessionResult {
+    pub success: bool,
+    pub guest_session_id: String,
+    #[serde(with = "crate::util::date_time_utc")]
+    pub expires_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<GuestSessionResult> for Session {
+    fn from(value: GuestSessionResult) -> Self {
+        Self::Guest(value.guest_session_id)
+    }
+}
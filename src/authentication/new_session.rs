@@ -0,0 +1,187 @@
+use std::borrow::Cow;
+
+use crate::client::Method;
+
+/// Command to create a session from a request token approved by the user.
+///
+/// ```rust
+/// use tmdb_api::prelude::Command;
+/// use tmdb_api::authentication::Session;
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::authentication::new_session::NewSession;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let mut client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into());
+///     let cmd = NewSession::new("request-token".into());
+///     match cmd.execute(&client).await {
+///         Ok(res) => client.set_session(Some(Session::from(res))),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct NewSession {
+    /// Request token approved by the user.
+    pub request_token: String,
+}
+
+impl NewSession {
+    pub fn new(request_token: String) -> Self {
+        Self { request_token }
+    }
+}
+
+impl crate::prelude::Command for NewSession {
+    type Output = super::SessionResult;
+
+    fn method(&self) -> Method {
+        Method::Post
+    }
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/authentication/session/new")
+    }
+
+    fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
+        Vec::new()
+    }
+
+    fn body(&self) -> Option<serde_json::Value> {
+        Some(serde_json::json!({ "request_token": self.request_token }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use mockito::Matcher;
+
+    use crate::authentication::Session;
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::prelude::Command;
+
+    use super::NewSession;
+
+    /// Command acting on behalf of a user.
+    struct AccountStates;
+
+    impl Command for AccountStates {
+        type Output = serde_json::Value;
+
+        fn needs_session(&self) -> bool {
+            true
+        }
+
+        fn path(&self) -> Cow<'static, str> {
+            Cow::Borrowed("/movie/550/account_states")
+        }
+
+        fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
+            Vec::new()
+        }
+    }
+
+    #[tokio::test]
+    async fn it_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("POST", "/authentication/session/new")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .match_body(Matcher::Json(serde_json::json!({
+                "request_token": "ff5c7eeb5a8870efe3cd7fc5c282cffd26800ecd",
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/authentication-session.json"))
+            .create_async()
+            .await;
+
+        let result = NewSession::new("ff5c7eeb5a8870efe3cd7fc5c282cffd26800ecd".into())
+            .execute(&client)
+            .await
+            .unwrap();
+        assert!(result.success);
+        assert_eq!(
+            Session::from(result),
+            Session::User("79191836ddaa0da3df76a5ffef6f07ad6ab0c641".into())
+        );
+    }
+
+    #[tokio::test]
+    async fn should_attach_session_to_next_commands() {
+        let mut server = mockito::Server::new_async().await;
+        let mut client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _session = server
+            .mock("POST", "/authentication/session/new")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/authentication-session.json"))
+            .create_async()
+            .await;
+        let next = server
+            .mock("GET", "/movie/550/account_states")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("api_key".into(), "secret".into()),
+                Matcher::UrlEncoded(
+                    "session_id".into(),
+                    "79191836ddaa0da3df76a5ffef6f07ad6ab0c641".into(),
+                ),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body("{}")
+            .create_async()
+            .await;
+
+        let result = NewSession::new("token".into())
+            .execute(&client)
+            .await
+            .unwrap();
+        client.set_session(Some(result.into()));
+
+        AccountStates.execute(&client).await.unwrap();
+        next.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn token_not_approved() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("POST", "/authentication/session/new")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(401)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"success":false,"status_code":17,"status_message":"Session denied."}"#)
+            .create_async()
+            .await;
+
+        let err = NewSession::new("token".into())
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 17);
+    }
+}
@@ -0,0 +1,183 @@
+use std::borrow::Cow;
+
+use crate::client::Method;
+
+/// Command to approve a request token with the user's TMDB credentials.
+///
+/// ```rust
+/// use tmdb_api::prelude::Command;
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::authentication::validate_request_token::ValidateRequestToken;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into());
+///     let cmd = ValidateRequestToken::new("username".into(), "password".into(), "request-token".into());
+///     let result = cmd.execute(&client).await;
+///     match result {
+///         Ok(res) => println!("found: {:#?}", res),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+#[derive(Clone, Default)]
+pub struct ValidateRequestToken {
+    pub username: String,
+    pub password: String,
+    /// Request token created with [`NewRequestToken`](super::new_request_token::NewRequestToken).
+    pub request_token: String,
+}
+
+impl std::fmt::Debug for ValidateRequestToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ValidateRequestToken")
+            .field("username", &self.username)
+            .field("password", &"***")
+            .field("request_token", &self.request_token)
+            .finish()
+    }
+}
+
+impl ValidateRequestToken {
+    pub fn new(username: String, password: String, request_token: String) -> Self {
+        Self {
+            username,
+            password,
+            request_token,
+        }
+    }
+}
+
+impl crate::prelude::Command for ValidateRequestToken {
+    type Output = super::RequestToken;
+
+    fn method(&self) -> Method {
+        Method::Post
+    }
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/authentication/token/validate_with_login")
+    }
+
+    fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
+        Vec::new()
+    }
+
+    fn body(&self) -> Option<serde_json::Value> {
+        Some(serde_json::json!({
+            "username": self.username,
+            "password": self.password,
+            "request_token": self.request_token,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::prelude::Command;
+
+    use super::ValidateRequestToken;
+
+    #[test]
+    fn should_hide_password() {
+        let cmd = ValidateRequestToken::new("john".into(), "secret".into(), "token".into());
+        let debug = format!("{cmd:?}");
+        assert!(debug.contains("john"));
+        assert!(!debug.contains("secret"));
+    }
+
+    #[tokio::test]
+    async fn it_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("POST", "/authentication/token/validate_with_login")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .match_body(Matcher::Json(serde_json::json!({
+                "username": "john",
+                "password": "doe",
+                "request_token": "ff5c7eeb5a8870efe3cd7fc5c282cffd26800ecd",
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!(
+                "../../assets/authentication-request-token.json"
+            ))
+            .create_async()
+            .await;
+
+        let result = ValidateRequestToken::new(
+            "john".into(),
+            "doe".into(),
+            "ff5c7eeb5a8870efe3cd7fc5c282cffd26800ecd".into(),
+        )
+        .execute(&client)
+        .await
+        .unwrap();
+        assert!(result.success);
+    }
+
+    #[tokio::test]
+    async fn invalid_credentials() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("POST", "/authentication/token/validate_with_login")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(401)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!(
+                "../../assets/authentication-invalid-credentials.json"
+            ))
+            .create_async()
+            .await;
+
+        let err = ValidateRequestToken::new("john".into(), "doe".into(), "token".into())
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 30);
+    }
+
+    #[tokio::test]
+    async fn resource_not_found() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("POST", "/authentication/token/validate_with_login")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/resource-not-found.json"))
+            .create_async()
+            .await;
+
+        let err = ValidateRequestToken::new("john".into(), "doe".into(), "token".into())
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 34);
+    }
+}
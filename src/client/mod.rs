@@ -3,10 +3,33 @@ pub mod reqwest;
 
 use std::borrow::Cow;
 
-pub use self::prelude::Executor;
+pub use self::prelude::{Executor, Method};
+
+use crate::authentication::Session;
 pub type ReqwestClient = Client<reqwest::ReqwestExecutor>;
 
 const BASE_URL: &str = "https://api.themoviedb.org/3";
+const BASE_URL_V4: &str = "https://api.themoviedb.org/4";
+
+/// Version of the API the client talks to.
+///
+/// The v3 API authenticates with the `api_key` query param, while the v4 API expects a bearer
+/// token: the user access token when one is set, the API read access token otherwise.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ApiVersion {
+    #[default]
+    V3,
+    V4,
+}
+
+impl ApiVersion {
+    pub fn base_url(&self) -> &'static str {
+        match self {
+            Self::V3 => BASE_URL,
+            Self::V4 => BASE_URL_V4,
+        }
+    }
+}
 
 #[derive(Debug, thiserror::Error)]
 pub enum ClientBuilderError {
@@ -19,6 +42,9 @@ pub struct ClientBuilder<E: prelude::Executor> {
     base_url: Cow<'static, str>,
     executor: Option<E>,
     api_key: Option<String>,
+    api_version: ApiVersion,
+    access_token: Option<String>,
+    session: Option<Session>,
 }
 
 impl<E: prelude::Executor> ClientBuilder<E> {
@@ -49,8 +75,39 @@ impl<E: prelude::Executor> ClientBuilder<E> {
         self.api_key = Some(value);
     }
 
+    pub fn with_api_version(mut self, value: ApiVersion) -> Self {
+        self.api_version = value;
+        self
+    }
+
+    pub fn set_api_version(&mut self, value: ApiVersion) {
+        self.api_version = value;
+    }
+
+    pub fn with_access_token(mut self, value: String) -> Self {
+        self.access_token = Some(value);
+        self
+    }
+
+    pub fn set_access_token(&mut self, value: String) {
+        self.access_token = Some(value);
+    }
+
+    pub fn with_session(mut self, value: Option<Session>) -> Self {
+        self.session = value;
+        self
+    }
+
+    pub fn set_session(&mut self, value: Option<Session>) {
+        self.session = value;
+    }
+
     pub fn build(self) -> Result<Client<E>, ClientBuilderError> {
-        let base_url = self.base_url;
+        let base_url = if self.base_url.is_empty() {
+            Cow::Borrowed(self.api_version.base_url())
+        } else {
+            self.base_url
+        };
         let executor = self.executor.unwrap_or_default();
         let api_key = self.api_key.ok_or(ClientBuilderError::MissingApiKey)?;
 
@@ -58,6 +115,9 @@ impl<E: prelude::Executor> ClientBuilder<E> {
             executor,
             base_url,
             api_key,
+            api_version: self.api_version,
+            access_token: self.access_token,
+            session: self.session,
         })
     }
 }
@@ -70,10 +130,26 @@ impl<E: prelude::Executor> ClientBuilder<E> {
 ///
 /// let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into());
 /// ```
+///
+/// To use the v4 API, build it with the API read access token.
+///
+/// ```rust
+/// use tmdb_api::client::{ApiVersion, Client};
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+///
+/// let client = Client::<ReqwestExecutor>::builder()
+///     .with_api_version(ApiVersion::V4)
+///     .with_api_key("this-is-my-read-access-token".into())
+///     .build()
+///     .unwrap();
+/// ```
 pub struct Client<E> {
     executor: E,
     base_url: Cow<'static, str>,
     api_key: String,
+    api_version: ApiVersion,
+    access_token: Option<String>,
+    session: Option<Session>,
 }
 
 impl<E: Executor> Client<E> {
@@ -86,6 +162,9 @@ impl<E: Executor> Client<E> {
             executor: E::default(),
             base_url: Cow::Borrowed(BASE_URL),
             api_key,
+            api_version: ApiVersion::V3,
+            access_token: None,
+            session: None,
         }
     }
 
@@ -99,14 +178,64 @@ impl<E: Executor> Client<E> {
         &self.base_url
     }
 
+    pub fn api_version(&self) -> ApiVersion {
+        self.api_version
+    }
+
+    /// User access token used as bearer token by the v4 API.
+    pub fn access_token(&self) -> Option<&str> {
+        self.access_token.as_deref()
+    }
+
+    pub fn with_access_token(mut self, value: String) -> Self {
+        self.access_token = Some(value);
+        self
+    }
+
+    pub fn set_access_token(&mut self, value: Option<String>) {
+        self.access_token = value;
+    }
+
+    /// Session attached to the commands acting on behalf of a user, when they don't specify one.
+    pub fn session(&self) -> Option<&Session> {
+        self.session.as_ref()
+    }
+
+    pub fn with_session(mut self, value: Option<Session>) -> Self {
+        self.session = value;
+        self
+    }
+
+    pub fn set_session(&mut self, value: Option<Session>) {
+        self.session = value;
+    }
+
     pub async fn execute<T: serde::de::DeserializeOwned>(
         &self,
         path: &str,
+        params: Vec<(&str, Cow<'_, str>)>,
+    ) -> Result<T, crate::error::Error> {
+        self.send(Method::Get, path, params, None).await
+    }
+
+    pub async fn send<T: serde::de::DeserializeOwned>(
+        &self,
+        method: Method,
+        path: &str,
         mut params: Vec<(&str, Cow<'_, str>)>,
+        body: Option<serde_json::Value>,
     ) -> Result<T, crate::error::Error> {
+        let url = format!("{}{}", self.base_url, path);
+        if self.api_version == ApiVersion::V4 {
+            let token = self.access_token.as_deref().unwrap_or(&self.api_key);
+            return self
+                .executor
+                .send(method, &url, params, body, Some(token))
+                .await;
+        }
+
         params.push(("api_key", Cow::Borrowed(self.api_key.as_str())));
 
-        let url = format!("{}{}", self.base_url, path);
-        self.executor.execute(&url, params).await
+        self.executor.send(method, &url, params, body, None).await
     }
 }
@@ -22,11 +22,35 @@
 
 use std::borrow::Cow;
 
+/// HTTP method used to reach an endpoint.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Method {
+    #[default]
+    Get,
+    Post,
+    Put,
+    Delete,
+}
+
 #[async_trait::async_trait]
 pub trait Executor: Default + Send + Sync {
     async fn execute<T: serde::de::DeserializeOwned>(
         &self,
         url: &str,
         params: Vec<(&str, Cow<'_, str>)>,
+    ) -> Result<T, crate::error::Error> {
+        self.send(Method::Get, url, params, None, None).await
+    }
+
+    /// Sends a request with the given method, adding the body as json when provided.
+    ///
+    /// When a token is provided, it's sent as a bearer token in the `Authorization` header.
+    async fn send<T: serde::de::DeserializeOwned>(
+        &self,
+        method: Method,
+        url: &str,
+        params: Vec<(&str, Cow<'_, str>)>,
+        body: Option<serde_json::Value>,
+        token: Option<&str>,
     ) -> Result<T, crate::error::Error>;
 }
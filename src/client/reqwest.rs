@@ -19,17 +19,35 @@ impl From<reqwest::Error> for crate::error::Error {
     }
 }
 
+impl From<super::prelude::Method> for reqwest::Method {
+    fn from(value: super::prelude::Method) -> Self {
+        match value {
+            super::prelude::Method::Get => reqwest::Method::GET,
+            super::prelude::Method::Post => reqwest::Method::POST,
+            super::prelude::Method::Put => reqwest::Method::PUT,
+            super::prelude::Method::Delete => reqwest::Method::DELETE,
+        }
+    }
+}
+
 #[async_trait::async_trait]
 impl super::prelude::Executor for ReqwestExecutor {
-    async fn execute<T: serde::de::DeserializeOwned>(
+    async fn send<T: serde::de::DeserializeOwned>(
         &self,
+        method: super::prelude::Method,
         url: &str,
         params: Vec<(&str, Cow<'_, str>)>,
+        body: Option<serde_json::Value>,
+        token: Option<&str>,
     ) -> Result<T, crate::error::Error> {
-        let res = self
-            .inner
-            .get(url)
-            .query(&params)
+        let mut req = self.inner.request(method.into(), url).query(&params);
+        if let Some(token) = token {
+            req = req.bearer_auth(token);
+        }
+        if let Some(ref body) = body {
+            req = req.json(body);
+        }
+        let res = req
             .send()
             .await
             .map_err(|err| crate::error::Error::Request {
@@ -1,5 +1,6 @@
 use std::borrow::Cow;
 
+use crate::common::id::{CollectionId, MovieId};
 use crate::common::MediaType;
 
 /// Command to get the details of a collection
@@ -24,7 +25,7 @@ use crate::common::MediaType;
 #[derive(Clone, Debug, Default)]
 pub struct CollectionDetails {
     /// ID of the collection
-    pub collection_id: u64,
+    pub collection_id: CollectionId,
     /// ISO 639-1 value to display translated data for the fields that support it.
     pub language: Option<String>,
 }
@@ -38,7 +39,7 @@ pub struct CollectionDetailsResult {
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct Media {
-    pub id: u64,
+    pub id: MovieId,
     pub media_type: MediaType,
     pub title: String,
     pub original_language: String,
@@ -63,9 +64,9 @@ pub struct Media {
 }
 
 impl CollectionDetails {
-    pub fn new(collection_id: u64) -> Self {
+    pub fn new(collection_id: impl Into<CollectionId>) -> Self {
         Self {
-            collection_id,
+            collection_id: collection_id.into(),
             language: None,
         }
     }
@@ -98,9 +99,10 @@ mod tests {
 
     use crate::client::reqwest::ReqwestExecutor;
     use crate::client::Client;
+    use crate::common::MediaType;
     use crate::prelude::Command;
 
-    use super::CollectionDetails;
+    use super::{CollectionDetails, CollectionDetailsResult};
 
     #[tokio::test]
     async fn it_works() {
@@ -124,6 +126,17 @@ mod tests {
         assert_eq!(result.inner.id, 10);
     }
 
+    #[test]
+    fn with_unknown_values() {
+        let mut value: serde_json::Value =
+            serde_json::from_str(include_str!("../../assets/collection-details.json")).unwrap();
+        value["parts"][0]["media_type"] = "short".into();
+        let result: CollectionDetailsResult = serde_json::from_value(value).unwrap();
+        let item = result.parts.first().unwrap();
+        assert_eq!(item.media_type, MediaType::Unknown("short".into()));
+        assert_eq!(item.media_type.to_string(), "short");
+    }
+
     #[tokio::test]
     async fn invalid_api_key() {
         let mut server = mockito::Server::new_async().await;
@@ -1,11 +1,27 @@
 #[cfg(feature = "commands")]
 pub mod details;
+#[cfg(feature = "commands")]
+pub mod search;
+
+use crate::common::id::CollectionId;
 
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct CollectionBase {
-    pub id: u64,
+    pub id: CollectionId,
     pub name: String,
     pub overview: Option<String>,
     pub poster_path: Option<String>,
     pub backdrop_path: Option<String>,
 }
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct CollectionShort {
+    #[serde(flatten)]
+    pub inner: CollectionBase,
+    #[serde(default)]
+    pub adult: bool,
+    #[serde(default)]
+    pub original_language: Option<String>,
+    #[serde(default)]
+    pub original_name: Option<String>,
+}
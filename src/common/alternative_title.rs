@@ -0,0 +1,11 @@
+/// Title of a movie or a tv show in a given country.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct AlternativeTitle {
+    pub iso_3166_1: String,
+    pub title: String,
+    #[serde(
+        deserialize_with = "crate::util::empty_string::deserialize",
+        rename = "type"
+    )]
+    pub kind: Option<String>,
+}
@@ -0,0 +1,23 @@
+/// Changes made on a field of a movie, tv show or season.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct Change {
+    pub key: String,
+    pub items: Vec<ChangeItem>,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ChangeItem {
+    pub id: String,
+    pub action: String,
+    pub time: chrono::DateTime<chrono::Utc>,
+    #[serde(default, deserialize_with = "crate::util::empty_string::deserialize")]
+    pub iso_639_1: Option<String>,
+    #[serde(default, deserialize_with = "crate::util::empty_string::deserialize")]
+    pub iso_3166_1: Option<String>,
+    /// New value, its shape depends on the key of the change.
+    #[serde(default)]
+    pub value: Option<serde_json::Value>,
+    /// Previous value, its shape depends on the key of the change.
+    #[serde(default)]
+    pub original_value: Option<serde_json::Value>,
+}
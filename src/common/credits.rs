@@ -1,15 +1,16 @@
+use crate::common::department::Department;
 use crate::people::PersonShort;
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct CreditCommon {
     pub credit_id: String,
     pub adult: bool,
-    pub known_for_department: Option<String>,
+    pub known_for_department: Option<Department>,
     pub original_name: String,
     pub popularity: f64,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct Cast {
     #[serde(flatten)]
     pub credit: CreditCommon,
@@ -22,14 +23,154 @@ pub struct Cast {
     pub profile_path: Option<String>,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct Crew {
     #[serde(flatten)]
     pub credit: CreditCommon,
     #[serde(flatten)]
     pub person: PersonShort,
-    pub department: String,
+    pub department: Department,
     pub job: String,
     #[serde(default)]
     pub profile_path: Option<String>,
 }
+
+/// Character played by a person in a tv show, along with the number of episodes.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct AggregateRole {
+    pub credit_id: String,
+    pub character: String,
+    pub episode_count: u64,
+}
+
+/// Cast member of a tv show, aggregated over all the episodes.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct AggregateCast {
+    #[serde(flatten)]
+    pub person: PersonShort,
+    pub adult: bool,
+    pub known_for_department: Option<Department>,
+    pub original_name: String,
+    pub popularity: f64,
+    pub roles: Vec<AggregateRole>,
+    pub total_episode_count: u64,
+    pub order: u64,
+}
+
+/// Job of a person in a tv show, along with the number of episodes.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct AggregateJob {
+    pub credit_id: String,
+    pub job: String,
+    pub episode_count: u64,
+}
+
+/// Crew member of a tv show, aggregated over all the episodes.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct AggregateCrew {
+    #[serde(flatten)]
+    pub person: PersonShort,
+    pub adult: bool,
+    pub known_for_department: Option<Department>,
+    pub original_name: String,
+    pub popularity: f64,
+    pub department: Department,
+    pub jobs: Vec<AggregateJob>,
+    pub total_episode_count: u64,
+}
+
+/// Cast and crew of a tv show or season, aggregated over all the episodes.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct AggregateCredits {
+    pub id: u64,
+    pub cast: Vec<AggregateCast>,
+    pub crew: Vec<AggregateCrew>,
+}
+
+/// A crew credit, attached to a department and to one or more jobs.
+pub trait CrewCredit {
+    fn department(&self) -> &Department;
+
+    /// Whether one of the jobs of the credit matches the predicate.
+    fn has_job<F: Fn(&str) -> bool>(&self, predicate: F) -> bool;
+
+    fn is_director(&self) -> bool {
+        self.has_job(|job| job == "Director")
+    }
+
+    /// Whether the credit is in the writing department, whatever the writing job.
+    fn is_writer(&self) -> bool {
+        self.department() == &Department::Writing
+    }
+
+    fn is_composer(&self) -> bool {
+        self.has_job(|job| matches!(job, "Original Music Composer" | "Music" | "Composer"))
+    }
+
+    /// Whether the credit is a producer job, executive and co producers included.
+    fn is_producer(&self) -> bool {
+        self.department() == &Department::Production
+            && self.has_job(|job| job.ends_with("Producer"))
+    }
+}
+
+/// Helpers to pick the main crew members out of a list of crew credits.
+///
+/// ```rust
+/// use tmdb_api::common::credits::{CrewCredit, CrewList, Crew};
+///
+/// fn director_names(crew: &[Crew]) -> Vec<&str> {
+///     crew.directors()
+///         .into_iter()
+///         .map(|item| item.person.name.as_str())
+///         .collect()
+/// }
+/// ```
+pub trait CrewList {
+    type Item: CrewCredit;
+
+    fn directors(&self) -> Vec<&Self::Item>;
+    fn writers(&self) -> Vec<&Self::Item>;
+    fn composers(&self) -> Vec<&Self::Item>;
+    fn producers(&self) -> Vec<&Self::Item>;
+}
+
+impl<T: CrewCredit> CrewList for [T] {
+    type Item = T;
+
+    fn directors(&self) -> Vec<&T> {
+        self.iter().filter(|item| item.is_director()).collect()
+    }
+
+    fn writers(&self) -> Vec<&T> {
+        self.iter().filter(|item| item.is_writer()).collect()
+    }
+
+    fn composers(&self) -> Vec<&T> {
+        self.iter().filter(|item| item.is_composer()).collect()
+    }
+
+    fn producers(&self) -> Vec<&T> {
+        self.iter().filter(|item| item.is_producer()).collect()
+    }
+}
+
+impl CrewCredit for Crew {
+    fn department(&self) -> &Department {
+        &self.department
+    }
+
+    fn has_job<F: Fn(&str) -> bool>(&self, predicate: F) -> bool {
+        predicate(self.job.as_str())
+    }
+}
+
+impl CrewCredit for AggregateCrew {
+    fn department(&self) -> &Department {
+        &self.department
+    }
+
+    fn has_job<F: Fn(&str) -> bool>(&self, predicate: F) -> bool {
+        self.jobs.iter().any(|item| predicate(item.job.as_str()))
+    }
+}
@@ -0,0 +1,46 @@
+/// Department a crew member works in.
+///
+/// The variants are the departments listed by `/configuration/jobs`, plus `Acting`
+/// that is used for the `known_for_department` of actors.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub enum Department {
+    Acting,
+    Actors,
+    Art,
+    Camera,
+    #[serde(rename = "Costume & Make-Up")]
+    CostumeAndMakeUp,
+    Crew,
+    Directing,
+    Editing,
+    Lighting,
+    Production,
+    Sound,
+    #[serde(rename = "Visual Effects")]
+    VisualEffects,
+    Writing,
+    /// Department not known by this crate, with its raw value.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl std::fmt::Display for Department {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Acting => f.write_str("Acting"),
+            Self::Actors => f.write_str("Actors"),
+            Self::Art => f.write_str("Art"),
+            Self::Camera => f.write_str("Camera"),
+            Self::CostumeAndMakeUp => f.write_str("Costume & Make-Up"),
+            Self::Crew => f.write_str("Crew"),
+            Self::Directing => f.write_str("Directing"),
+            Self::Editing => f.write_str("Editing"),
+            Self::Lighting => f.write_str("Lighting"),
+            Self::Production => f.write_str("Production"),
+            Self::Sound => f.write_str("Sound"),
+            Self::VisualEffects => f.write_str("Visual Effects"),
+            Self::Writing => f.write_str("Writing"),
+            Self::Unknown(value) => f.write_str(value),
+        }
+    }
+}
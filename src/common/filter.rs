@@ -0,0 +1,99 @@
+use std::fmt;
+
+/// Values of a discover filter accepting several of them.
+///
+/// TMDB separates the values with `,` when all of them should match, and with `|` when any of
+/// them should match.
+///
+/// ```rust
+/// use tmdb_api::common::filter::Filter;
+///
+/// assert_eq!(Filter::all([28, 12]).to_string(), "28,12");
+/// assert_eq!(Filter::any([28, 12]).to_string(), "28|12");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Filter<T> {
+    /// Every value has to match.
+    All(Vec<T>),
+    /// At least one value has to match.
+    Any(Vec<T>),
+}
+
+impl<T> Filter<T> {
+    pub fn all<I: IntoIterator<Item = T>>(values: I) -> Self {
+        Self::All(values.into_iter().collect())
+    }
+
+    pub fn any<I: IntoIterator<Item = T>>(values: I) -> Self {
+        Self::Any(values.into_iter().collect())
+    }
+
+    pub fn values(&self) -> &[T] {
+        match self {
+            Self::All(values) | Self::Any(values) => values.as_slice(),
+        }
+    }
+
+    /// Converts the values, failing on the first one that can't be converted.
+    pub fn try_map<U, E, F: FnMut(T) -> Result<U, E>>(self, func: F) -> Result<Filter<U>, E> {
+        match self {
+            Self::All(values) => values
+                .into_iter()
+                .map(func)
+                .collect::<Result<_, _>>()
+                .map(Filter::All),
+            Self::Any(values) => values
+                .into_iter()
+                .map(func)
+                .collect::<Result<_, _>>()
+                .map(Filter::Any),
+        }
+    }
+}
+
+impl<T> From<Vec<T>> for Filter<T> {
+    fn from(values: Vec<T>) -> Self {
+        Self::All(values)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Filter<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let separator = match self {
+            Self::All(_) => ",",
+            Self::Any(_) => "|",
+        };
+        for (index, value) in self.values().iter().enumerate() {
+            if index > 0 {
+                f.write_str(separator)?;
+            }
+            write!(f, "{value}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Filter;
+
+    #[test]
+    fn should_display_single_value_without_separator() {
+        assert_eq!(Filter::all([213]).to_string(), "213");
+        assert_eq!(Filter::any([213]).to_string(), "213");
+    }
+
+    #[test]
+    fn should_keep_the_separator_when_converting() {
+        let filter = Filter::any([1, 2, 3]).try_map(|value| Ok::<_, ()>(value * 10));
+        assert_eq!(filter, Ok(Filter::any([10, 20, 30])));
+        let filter =
+            Filter::all([1, 2, 3]).try_map(|value| if value == 2 { Err(value) } else { Ok(value) });
+        assert_eq!(filter, Err(2));
+    }
+
+    #[test]
+    fn should_default_to_all_from_vec() {
+        assert_eq!(Filter::from(vec![1, 2, 3]).to_string(), "1,2,3");
+    }
+}
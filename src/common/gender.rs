@@ -0,0 +1,64 @@
+/// Gender of a person, as set on TMDB.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Gender {
+    #[default]
+    NotSet,
+    Female,
+    Male,
+    NonBinary,
+    /// Gender not known by this crate, with its raw code.
+    Unknown(u8),
+}
+
+impl From<u8> for Gender {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::NotSet,
+            1 => Self::Female,
+            2 => Self::Male,
+            3 => Self::NonBinary,
+            other => Self::Unknown(other),
+        }
+    }
+}
+
+impl From<Gender> for u8 {
+    fn from(value: Gender) -> Self {
+        match value {
+            Gender::NotSet => 0,
+            Gender::Female => 1,
+            Gender::Male => 2,
+            Gender::NonBinary => 3,
+            Gender::Unknown(other) => other,
+        }
+    }
+}
+
+impl serde::Serialize for Gender {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8(u8::from(*self))
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Gender {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // TMDB sends null for the people without gender
+        Option::<u8>::deserialize(deserializer)
+            .map(|value| value.map(Self::from).unwrap_or_default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Gender;
+
+    #[test]
+    fn should_deserialize_null_as_not_set() {
+        let value: Gender = serde_json::from_str("null").unwrap();
+        assert_eq!(value, Gender::NotSet);
+        let value: Gender = serde_json::from_str("3").unwrap();
+        assert_eq!(value, Gender::NonBinary);
+        let value: Gender = serde_json::from_str("7").unwrap();
+        assert_eq!(value, Gender::Unknown(7));
+    }
+}
@@ -0,0 +1,104 @@
+//! Typed identifiers, to avoid passing the ID of a tv show where a movie is expected.
+//!
+//! Each of them serializes as the raw number and can be built from a `u64`.
+//!
+//! ```rust
+//! use tmdb_api::common::id::MovieId;
+//!
+//! let id = MovieId::from(550);
+//! assert_eq!(id, 550);
+//! assert_eq!(id.to_string(), "550");
+//! assert_eq!(u64::from(id), 550);
+//! ```
+
+macro_rules! id_type {
+    ($(#[$meta:meta])* $name:ident) => {
+        $(#[$meta])*
+        #[derive(
+            Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize,
+        )]
+        #[serde(transparent)]
+        pub struct $name(pub u64);
+
+        impl $name {
+            pub fn value(self) -> u64 {
+                self.0
+            }
+        }
+
+        impl From<u64> for $name {
+            fn from(value: u64) -> Self {
+                Self(value)
+            }
+        }
+
+        impl From<$name> for u64 {
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+
+        impl PartialEq<u64> for $name {
+            fn eq(&self, other: &u64) -> bool {
+                self.0 == *other
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}", self.0)
+            }
+        }
+    };
+}
+
+id_type!(
+    /// ID of a movie.
+    MovieId
+);
+id_type!(
+    /// ID of a tv show.
+    TvId
+);
+id_type!(
+    /// ID of a person.
+    PersonId
+);
+id_type!(
+    /// ID of a company.
+    CompanyId
+);
+id_type!(
+    /// ID of a collection.
+    CollectionId
+);
+id_type!(
+    /// ID of a tv network.
+    NetworkId
+);
+id_type!(
+    /// ID of a keyword.
+    KeywordId
+);
+id_type!(
+    /// ID of a tv show episode.
+    EpisodeId
+);
+
+#[cfg(test)]
+mod tests {
+    use super::{MovieId, TvId};
+
+    #[test]
+    fn should_serialize_as_number() {
+        let id: MovieId = serde_json::from_str("550").unwrap();
+        assert_eq!(id, MovieId(550));
+        assert_eq!(serde_json::to_string(&id).unwrap(), "550");
+    }
+
+    #[test]
+    fn should_build_from_u64() {
+        assert_eq!(TvId::from(1399), TvId(1399));
+        assert_eq!(u64::from(TvId(1399)), 1399);
+    }
+}
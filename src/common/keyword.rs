@@ -1,5 +1,7 @@
+use crate::common::id::KeywordId;
+
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Keyword {
-    pub id: u64,
+    pub id: KeywordId,
     pub name: String,
 }
@@ -0,0 +1,118 @@
+use crate::movie::MovieShort;
+use crate::people::PersonWithKnownFor;
+use crate::tvshow::TVShowShort;
+use crate::util::tagged;
+
+/// Movie, tv show or person, as found in results mixing media types.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "media_type", rename_all = "lowercase")]
+pub enum MediaShort {
+    Movie(MovieShort),
+    Tv(TVShowShort),
+    Person(PersonWithKnownFor),
+    /// Media type not known by this crate.
+    #[serde(untagged)]
+    Unknown(UnknownMedia),
+}
+
+impl<'de> serde::Deserialize<'de> for MediaShort {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (tag, value) = tagged::deserialize(deserializer, "media_type")?;
+        match tag.as_deref() {
+            Some("movie") => tagged::from_value(value).map(Self::Movie),
+            Some("tv") => tagged::from_value(value).map(Self::Tv),
+            Some("person") => tagged::from_value(value).map(Self::Person),
+            _ => tagged::from_value(value).map(Self::Unknown),
+        }
+    }
+}
+
+/// Media of a type not known by this crate, with the fields all the media types share.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct UnknownMedia {
+    /// Raw media type, when provided.
+    #[serde(default)]
+    pub media_type: String,
+    pub id: u64,
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub name: Option<String>,
+}
+
+impl UnknownMedia {
+    /// Title, or name when there is no title.
+    pub fn title(&self) -> &str {
+        self.title
+            .as_deref()
+            .or(self.name.as_deref())
+            .unwrap_or_default()
+    }
+}
+
+impl MediaShort {
+    pub fn id(&self) -> u64 {
+        match self {
+            Self::Movie(inner) => inner.inner.id.value(),
+            Self::Tv(inner) => inner.inner.id.value(),
+            Self::Person(inner) => inner.inner.id.value(),
+            Self::Unknown(inner) => inner.id,
+        }
+    }
+
+    /// Title of the movie, name of the tv show or of the person.
+    pub fn title(&self) -> &str {
+        match self {
+            Self::Movie(inner) => inner.inner.title.as_str(),
+            Self::Tv(inner) => inner.inner.name.as_str(),
+            Self::Person(inner) => inner.inner.name.as_str(),
+            Self::Unknown(inner) => inner.title(),
+        }
+    }
+
+    pub fn as_movie(&self) -> Option<&MovieShort> {
+        match self {
+            Self::Movie(inner) => Some(inner),
+            _ => None,
+        }
+    }
+
+    pub fn as_tvshow(&self) -> Option<&TVShowShort> {
+        match self {
+            Self::Tv(inner) => Some(inner),
+            _ => None,
+        }
+    }
+
+    pub fn as_person(&self) -> Option<&PersonWithKnownFor> {
+        match self {
+            Self::Person(inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MediaShort;
+
+    #[test]
+    fn should_fail_on_malformed_known_media() {
+        let err =
+            serde_json::from_str::<MediaShort>(r#"{"media_type":"movie","id":550,"name":"oops"}"#)
+                .unwrap_err();
+        assert!(err.to_string().contains("missing field"), "{err}");
+    }
+
+    #[test]
+    fn should_fall_back_on_unknown_media_type() {
+        let value: MediaShort =
+            serde_json::from_str(r#"{"media_type":"podcast","id":42,"name":"Some Podcast"}"#)
+                .unwrap();
+        assert!(
+            matches!(&value, MediaShort::Unknown(inner) if inner.media_type == "podcast"),
+            "{value:?}"
+        );
+        assert_eq!(value.title(), "Some Podcast");
+    }
+}
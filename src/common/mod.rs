@@ -1,11 +1,20 @@
 use std::fmt::Display;
 
+pub mod alternative_title;
+pub mod change;
 pub mod country;
 pub mod credits;
+pub mod department;
+pub mod filter;
+pub mod gender;
+pub mod id;
 pub mod image;
 pub mod keyword;
 pub mod language;
+pub mod media;
+pub mod rating;
 pub mod release_date;
+pub mod review;
 pub mod status;
 pub mod video;
 
@@ -17,12 +26,43 @@ pub struct PaginatedResult<T> {
     pub results: Vec<T>,
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+/// Generic response of the endpoints that don't return any data.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct StatusResult {
+    #[serde(default)]
+    pub success: bool,
+    #[serde(default)]
+    pub status_code: Option<u16>,
+    #[serde(default)]
+    pub status_message: Option<String>,
+}
+
+/// Period the trending commands are computed on.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TimeWindow {
+    #[default]
+    Day,
+    Week,
+}
+
+impl Display for TimeWindow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Day => write!(f, "day"),
+            Self::Week => write!(f, "week"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(rename_all = "lowercase")]
 pub enum MediaType {
     Movie,
     Tv,
     Collection,
+    /// Media type not known by this crate, with its raw value.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 impl Display for MediaType {
@@ -31,6 +71,7 @@ impl Display for MediaType {
             MediaType::Movie => "movie",
             MediaType::Tv => "tv",
             MediaType::Collection => "collection",
+            MediaType::Unknown(value) => value.as_str(),
         };
 
         write!(f, "{}", s)
@@ -0,0 +1,84 @@
+/// Error returned when building a [`Rating`] out of the range accepted by TMDB.
+#[derive(Clone, Copy, Debug, PartialEq, thiserror::Error)]
+#[error("invalid rating {0}, expected a value between 0.5 and 10.0 with a step of 0.5")]
+pub struct InvalidRating(pub f32);
+
+/// Rating value accepted by TMDB: between `0.5` and `10.0`, with a step of `0.5`.
+///
+/// ```rust
+/// use tmdb_api::common::rating::Rating;
+///
+/// assert!(Rating::new(8.5).is_ok());
+/// assert!(Rating::new(8.2).is_err());
+/// assert!(Rating::new(0.0).is_err());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Serialize)]
+#[serde(transparent)]
+pub struct Rating(f32);
+
+impl Rating {
+    pub const MIN: f32 = 0.5;
+    pub const MAX: f32 = 10.0;
+    pub const STEP: f32 = 0.5;
+
+    pub fn new(value: f32) -> Result<Self, InvalidRating> {
+        if !(Self::MIN..=Self::MAX).contains(&value) || (value / Self::STEP).fract() != 0.0 {
+            return Err(InvalidRating(value));
+        }
+        Ok(Self(value))
+    }
+
+    pub fn value(&self) -> f32 {
+        self.0
+    }
+}
+
+impl TryFrom<f32> for Rating {
+    type Error = InvalidRating;
+
+    fn try_from(value: f32) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl From<Rating> for f32 {
+    fn from(value: Rating) -> Self {
+        value.0
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Rating {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = f32::deserialize(deserializer)?;
+        Self::new(value).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InvalidRating, Rating};
+
+    #[test]
+    fn should_accept_valid_values() {
+        for value in [0.5, 1.0, 4.5, 9.5, 10.0] {
+            assert_eq!(Rating::new(value).unwrap().value(), value);
+        }
+    }
+
+    #[test]
+    fn should_reject_invalid_values() {
+        for value in [-1.0, 0.0, 0.3, 7.25, 10.5, f32::NAN] {
+            assert!(Rating::new(value).is_err());
+        }
+        assert_eq!(Rating::try_from(11.0), Err(InvalidRating(11.0)));
+    }
+
+    #[test]
+    fn should_validate_when_deserializing() {
+        assert!(serde_json::from_str::<Rating>("8.5").is_ok());
+        assert!(serde_json::from_str::<Rating>("8.3").is_err());
+    }
+}
@@ -4,15 +4,62 @@ pub struct LocatedReleaseDates {
     pub release_dates: Vec<ReleaseDate>,
 }
 
-#[derive(Serialize_repr, Deserialize_repr, PartialEq, Debug)]
-#[repr(u8)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum ReleaseDateKind {
-    Premiere = 1,
-    TheatricalLimited = 2,
-    Theatrical = 3,
-    Digital = 4,
-    Physical = 5,
-    TV = 6,
+    Premiere,
+    TheatricalLimited,
+    Theatrical,
+    Digital,
+    Physical,
+    TV,
+    /// Kind not known by this crate, with its raw code.
+    Unknown(u8),
+}
+
+impl From<u8> for ReleaseDateKind {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => Self::Premiere,
+            2 => Self::TheatricalLimited,
+            3 => Self::Theatrical,
+            4 => Self::Digital,
+            5 => Self::Physical,
+            6 => Self::TV,
+            other => Self::Unknown(other),
+        }
+    }
+}
+
+impl From<ReleaseDateKind> for u8 {
+    fn from(value: ReleaseDateKind) -> Self {
+        match value {
+            ReleaseDateKind::Premiere => 1,
+            ReleaseDateKind::TheatricalLimited => 2,
+            ReleaseDateKind::Theatrical => 3,
+            ReleaseDateKind::Digital => 4,
+            ReleaseDateKind::Physical => 5,
+            ReleaseDateKind::TV => 6,
+            ReleaseDateKind::Unknown(other) => other,
+        }
+    }
+}
+
+impl serde::Serialize for ReleaseDateKind {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8(u8::from(*self))
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for ReleaseDateKind {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        u8::deserialize(deserializer).map(Self::from)
+    }
+}
+
+impl std::fmt::Display for ReleaseDateKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", u8::from(*self))
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize)]
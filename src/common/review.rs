@@ -0,0 +1,19 @@
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct AuthorDetails {
+    pub name: String,
+    pub username: String,
+    pub avatar_path: Option<String>,
+    pub rating: Option<f32>,
+}
+
+/// Review written by a user, for a movie or a tv show.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct Review {
+    pub id: String,
+    pub author: String,
+    pub author_details: AuthorDetails,
+    pub content: String,
+    pub url: String,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
@@ -1,4 +1,4 @@
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize, Serialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
 pub enum Status {
     Rumored,
     Planned,
@@ -8,4 +8,7 @@ pub enum Status {
     PostProduction,
     Released,
     Canceled,
+    /// Status not known by this crate, with its raw value.
+    #[serde(untagged)]
+    Unknown(String),
 }
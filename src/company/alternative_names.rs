@@ -1,5 +1,7 @@
 use std::borrow::Cow;
 
+use crate::common::id::CompanyId;
+
 /// Command to get details of a company
 ///
 /// ```rust
@@ -22,12 +24,14 @@ use std::borrow::Cow;
 #[derive(Clone, Debug, Default)]
 pub struct CompanyAlternativeNames {
     /// ID of the Company
-    pub company_id: u64,
+    pub company_id: CompanyId,
 }
 
 impl CompanyAlternativeNames {
-    pub fn new(company_id: u64) -> Self {
-        Self { company_id }
+    pub fn new(company_id: impl Into<CompanyId>) -> Self {
+        Self {
+            company_id: company_id.into(),
+        }
     }
 }
 
@@ -43,7 +47,7 @@ pub struct CompanyAlternativeName {
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct CompanyAlternativeNamesResult {
-    pub id: u64,
+    pub id: CompanyId,
     pub results: Vec<CompanyAlternativeName>,
 }
 
@@ -4,10 +4,14 @@ pub mod alternative_names;
 pub mod details;
 #[cfg(feature = "commands")]
 pub mod images;
+#[cfg(feature = "commands")]
+pub mod search;
+
+use crate::common::id::CompanyId;
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CompanyShort {
-    pub id: u64,
+    pub id: CompanyId,
     pub name: String,
     pub logo_path: Option<String>,
     #[serde(deserialize_with = "crate::util::empty_string::deserialize")]
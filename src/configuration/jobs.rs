@@ -25,7 +25,7 @@ pub struct Jobs {}
 
 #[derive(Debug, Deserialize)]
 pub struct JobsResult {
-    pub department: String,
+    pub department: crate::common::department::Department,
     pub jobs: Vec<String>,
 }
 
@@ -52,6 +52,7 @@ mod tests {
     use mockito::Matcher;
 
     use crate::client::reqwest::ReqwestExecutor;
+    use crate::common::department::Department;
     use crate::prelude::Command;
     use crate::Client;
 
@@ -77,6 +78,9 @@ mod tests {
 
         let result = Jobs::default().execute(&client).await.unwrap();
         assert!(!result.is_empty());
+        assert!(result
+            .iter()
+            .all(|item| !matches!(item.department, Department::Unknown(_))));
     }
 
     #[tokio::test]
@@ -7,8 +7,6 @@
 
 #[macro_use]
 extern crate serde;
-#[macro_use]
-extern crate serde_repr;
 
 /// The used version of reqwest
 #[cfg(feature = "commands")]
@@ -17,6 +15,8 @@ pub use reqwest;
 #[cfg(feature = "commands")]
 pub use client::Client;
 
+pub mod account;
+pub mod authentication;
 pub mod certification;
 pub mod changes;
 #[cfg(feature = "commands")]
@@ -25,11 +25,15 @@ pub mod collection;
 pub mod company;
 pub mod error;
 pub mod genre;
+pub mod keyword;
+pub mod list;
 pub mod movie;
 pub mod people;
 #[cfg(feature = "commands")]
 pub mod prelude;
+pub mod trending;
 pub mod tvshow;
+pub mod v4;
 pub mod watch_provider;
 
 pub mod common;
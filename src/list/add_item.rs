@@ -0,0 +1,145 @@
+use std::borrow::Cow;
+
+use crate::authentication::Session;
+use crate::client::Method;
+use crate::common::id::MovieId;
+use crate::common::StatusResult;
+
+/// Add a movie to a list.
+///
+/// ```rust
+/// use tmdb_api::prelude::Command;
+/// use tmdb_api::authentication::Session;
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::list::add_item::ListAddItem;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into())
+///         .with_session(Some(Session::User("session-id".into())));
+///     let result = ListAddItem::new(5861, 550).execute(&client).await;
+///     match result {
+///         Ok(res) => println!("found: {:#?}", res),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ListAddItem {
+    /// ID of the list.
+    pub list_id: u64,
+    /// ID of the movie.
+    pub media_id: MovieId,
+    /// Session to use instead of the one of the client.
+    pub session: Option<Session>,
+}
+
+impl ListAddItem {
+    pub fn new(list_id: u64, media_id: impl Into<MovieId>) -> Self {
+        Self {
+            list_id,
+            media_id: media_id.into(),
+            session: None,
+        }
+    }
+
+    pub fn with_session(mut self, value: Option<Session>) -> Self {
+        self.session = value;
+        self
+    }
+}
+
+impl crate::prelude::Command for ListAddItem {
+    type Output = StatusResult;
+
+    fn needs_session(&self) -> bool {
+        true
+    }
+
+    fn method(&self) -> Method {
+        Method::Post
+    }
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Owned(format!("/list/{}/add_item", self.list_id))
+    }
+
+    fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
+        let mut res = Vec::new();
+        if let Some(ref session) = self.session {
+            let (name, value) = session.as_param();
+            res.push((name, Cow::Borrowed(value)));
+        }
+        res
+    }
+
+    fn body(&self) -> Option<serde_json::Value> {
+        Some(serde_json::json!({ "media_id": self.media_id }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+
+    use crate::authentication::Session;
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::prelude::Command;
+
+    use super::ListAddItem;
+
+    #[tokio::test]
+    async fn it_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .with_session(Some(Session::User("session".into())))
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("POST", "/list/5861/add_item")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("api_key".into(), "secret".into()),
+                Matcher::UrlEncoded("session_id".into(), "session".into()),
+            ]))
+            .match_body(Matcher::Json(serde_json::json!({ "media_id": 550 })))
+            .with_status(201)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/list-status.json"))
+            .create_async()
+            .await;
+
+        let result = ListAddItem::new(5861, 550).execute(&client).await.unwrap();
+        assert_eq!(result.status_code, Some(12));
+    }
+
+    #[tokio::test]
+    async fn resource_not_found() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("POST", "/list/5861/add_item")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/resource-not-found.json"))
+            .create_async()
+            .await;
+
+        let err = ListAddItem::new(5861, 550)
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 34);
+    }
+}
@@ -0,0 +1,112 @@
+use std::borrow::Cow;
+
+use crate::common::id::MovieId;
+
+/// Check if a movie has already been added to a list.
+///
+/// ```rust
+/// use tmdb_api::prelude::Command;
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::list::item_status::ListItemStatus;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into());
+///     let result = ListItemStatus::new(1, 550).execute(&client).await;
+///     match result {
+///         Ok(res) => println!("found: {:#?}", res),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ListItemStatus {
+    /// ID of the list.
+    pub list_id: u64,
+    /// ID of the movie to look for.
+    pub movie_id: MovieId,
+}
+
+impl ListItemStatus {
+    pub fn new(list_id: u64, movie_id: impl Into<MovieId>) -> Self {
+        Self {
+            list_id,
+            movie_id: movie_id.into(),
+        }
+    }
+}
+
+impl crate::prelude::Command for ListItemStatus {
+    type Output = super::ItemStatus;
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Owned(format!("/list/{}/item_status", self.list_id))
+    }
+
+    fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
+        vec![("movie_id", Cow::Owned(self.movie_id.to_string()))]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::prelude::Command;
+
+    use super::ListItemStatus;
+
+    #[tokio::test]
+    async fn it_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/list/1/item_status")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("api_key".into(), "secret".into()),
+                Matcher::UrlEncoded("movie_id".into(), "550".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/list-item-status.json"))
+            .create_async()
+            .await;
+
+        let result = ListItemStatus::new(1, 550).execute(&client).await.unwrap();
+        assert!(result.item_present);
+    }
+
+    #[tokio::test]
+    async fn resource_not_found() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/list/1/item_status")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/resource-not-found.json"))
+            .create_async()
+            .await;
+
+        let err = ListItemStatus::new(1, 550)
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 34);
+    }
+}
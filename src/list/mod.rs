@@ -0,0 +1,58 @@
+#[cfg(feature = "commands")]
+pub mod add_item;
+#[cfg(feature = "commands")]
+pub mod clear;
+#[cfg(feature = "commands")]
+pub mod create;
+#[cfg(feature = "commands")]
+pub mod delete;
+#[cfg(feature = "commands")]
+pub mod details;
+#[cfg(feature = "commands")]
+pub mod item_status;
+#[cfg(feature = "commands")]
+pub mod remove_item;
+
+use crate::common::media::MediaShort;
+use crate::common::StatusResult;
+
+/// List as returned when listing the lists a movie or a tv show belongs to.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ListShort {
+    pub id: u64,
+    pub name: String,
+    #[serde(deserialize_with = "crate::util::empty_string::deserialize")]
+    pub description: Option<String>,
+    pub list_type: String,
+    pub poster_path: Option<String>,
+    pub iso_639_1: String,
+    pub item_count: u64,
+    pub favorite_count: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct List {
+    #[serde(deserialize_with = "crate::util::number_or_string::deserialize")]
+    pub id: u64,
+    pub name: String,
+    #[serde(default, deserialize_with = "crate::util::empty_string::deserialize")]
+    pub description: Option<String>,
+    pub created_by: String,
+    pub iso_639_1: String,
+    pub poster_path: Option<String>,
+    pub favorite_count: u64,
+    pub item_count: u64,
+    pub items: Vec<MediaShort>,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ItemStatus {
+    pub item_present: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ListCreated {
+    #[serde(flatten)]
+    pub status: StatusResult,
+    pub list_id: u64,
+}
@@ -1,5 +1,8 @@
 use std::borrow::Cow;
 
+use crate::common::alternative_title::AlternativeTitle;
+use crate::common::id::MovieId;
+
 /// Command to get alternative titles for a movie
 ///
 /// ```rust
@@ -22,15 +25,15 @@ use std::borrow::Cow;
 #[derive(Clone, Debug, Default)]
 pub struct MovieAlternativeTitles {
     /// ID of the Movie
-    pub movie_id: u64,
+    pub movie_id: MovieId,
     /// The country to filter the alternative titles
     pub country: Option<String>,
 }
 
 impl MovieAlternativeTitles {
-    pub fn new(movie_id: u64) -> Self {
+    pub fn new(movie_id: impl Into<MovieId>) -> Self {
         Self {
-            movie_id,
+            movie_id: movie_id.into(),
             country: None,
         }
     }
@@ -41,21 +44,13 @@ impl MovieAlternativeTitles {
     }
 }
 
-#[derive(Debug, Deserialize, Serialize)]
-pub struct MovieAlternativeTitle {
-    pub iso_3166_1: String,
-    pub title: String,
-    #[serde(
-        deserialize_with = "crate::util::empty_string::deserialize",
-        rename = "type"
-    )]
-    pub kind: Option<String>,
-}
+#[deprecated = "Use common::alternative_title::AlternativeTitle instead."]
+pub type MovieAlternativeTitle = AlternativeTitle;
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct MovieAlternativeTitlesResult {
-    pub id: u64,
-    pub titles: Vec<MovieAlternativeTitle>,
+    pub id: MovieId,
+    pub titles: Vec<AlternativeTitle>,
 }
 
 impl crate::prelude::Command for MovieAlternativeTitles {
@@ -2,6 +2,9 @@ use std::borrow::Cow;
 
 use chrono::NaiveDate;
 
+use crate::common::change::{Change, ChangeItem};
+use crate::common::id::MovieId;
+
 /// Command to get changes for a movie
 ///
 /// ```rust
@@ -24,7 +27,7 @@ use chrono::NaiveDate;
 #[derive(Clone, Debug, Default)]
 pub struct MovieChanges {
     /// ID of the Movie
-    pub movie_id: u64,
+    pub movie_id: MovieId,
     /// Filter the results with a start date.
     pub start_date: Option<NaiveDate>,
     /// Filter the results with a end date.
@@ -34,9 +37,9 @@ pub struct MovieChanges {
 }
 
 impl MovieChanges {
-    pub fn new(movie_id: u64) -> Self {
+    pub fn new(movie_id: impl Into<MovieId>) -> Self {
         Self {
-            movie_id,
+            movie_id: movie_id.into(),
             start_date: None,
             end_date: None,
             page: None,
@@ -59,27 +62,17 @@ impl MovieChanges {
     }
 }
 
-#[derive(Debug, Deserialize, Serialize)]
-pub struct MovieChange {
-    pub key: String,
-    pub items: Vec<MovieChangeItem>,
-}
+#[deprecated = "Use common::change::Change instead."]
+pub type MovieChange = Change;
 
-#[derive(Debug, Deserialize, Serialize)]
-pub struct MovieChangeItem {
-    pub id: String,
-    pub action: String,
-    pub time: chrono::DateTime<chrono::Utc>,
-    pub iso_639_1: String,
-    pub iso_3166_1: String,
-    // TODO handle really dynamic kind of values
-    // pub value: String,
-    // pub original_value: String,
-}
+/// Breaking change: `iso_639_1` and `iso_3166_1` are now optional, TMDB leaving them out of
+/// the changes that don't depend on the language or the country.
+#[deprecated = "Use common::change::ChangeItem instead."]
+pub type MovieChangeItem = ChangeItem;
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct MovieChangesResult {
-    pub changes: Vec<MovieChange>,
+    pub changes: Vec<Change>,
 }
 
 impl crate::prelude::Command for MovieChanges {
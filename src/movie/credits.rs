@@ -1,6 +1,7 @@
 use std::borrow::Cow;
 
 use crate::common::credits::{Cast, Crew};
+use crate::common::id::MovieId;
 
 /// Command to get alternative titles for a movie
 ///
@@ -24,15 +25,15 @@ use crate::common::credits::{Cast, Crew};
 #[derive(Clone, Debug, Default)]
 pub struct MovieCredits {
     /// ID of the Movie
-    pub movie_id: u64,
+    pub movie_id: MovieId,
     /// ISO 639-1 value to display translated data for the fields that support it.
     pub language: Option<String>,
 }
 
 impl MovieCredits {
-    pub fn new(movie_id: u64) -> Self {
+    pub fn new(movie_id: impl Into<MovieId>) -> Self {
         Self {
-            movie_id,
+            movie_id: movie_id.into(),
             language: None,
         }
     }
@@ -45,7 +46,7 @@ impl MovieCredits {
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct MovieCreditsResult {
-    pub id: u64,
+    pub id: MovieId,
     pub cast: Vec<Cast>,
     pub crew: Vec<Crew>,
 }
@@ -72,6 +73,8 @@ mod tests {
 
     use crate::client::reqwest::ReqwestExecutor;
     use crate::client::Client;
+    use crate::common::credits::{Crew, CrewList};
+    use crate::common::department::Department;
     use crate::prelude::Command;
 
     use super::MovieCredits;
@@ -96,6 +99,26 @@ mod tests {
 
         let result = MovieCredits::new(3).execute(&client).await.unwrap();
         assert_eq!(result.id, 550);
+        let names = |items: Vec<&Crew>| {
+            items
+                .into_iter()
+                .map(|item| item.person.name.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(names(result.crew.directors()), vec!["David Fincher"]);
+        assert_eq!(
+            names(result.crew.writers()),
+            vec!["Chuck Palahniuk", "Jim Uhls"]
+        );
+        assert_eq!(
+            names(result.crew.composers()),
+            vec!["John King", "Michael Simpson"]
+        );
+        assert_eq!(result.crew.producers().len(), 5);
+        assert_eq!(
+            result.crew[0].credit.known_for_department,
+            Some(Department::Production)
+        );
     }
 
     #[tokio::test]
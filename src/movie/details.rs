@@ -1,5 +1,7 @@
 use std::borrow::Cow;
 
+use crate::common::id::MovieId;
+
 /// Command to get the details of a movie
 ///
 /// ```rust
@@ -22,15 +24,15 @@ use std::borrow::Cow;
 #[derive(Clone, Debug, Default)]
 pub struct MovieDetails {
     /// ID of the movie.
-    pub movie_id: u64,
+    pub movie_id: MovieId,
     /// ISO 639-1 value to display translated data for the fields that support it.
     pub language: Option<String>,
 }
 
 impl MovieDetails {
-    pub fn new(movie_id: u64) -> Self {
+    pub fn new(movie_id: impl Into<MovieId>) -> Self {
         Self {
-            movie_id,
+            movie_id: movie_id.into(),
             language: None,
         }
     }
@@ -61,8 +63,11 @@ impl crate::prelude::Command for MovieDetails {
 mod tests {
     use mockito::Matcher;
 
+    use crate::authentication::Session;
     use crate::client::reqwest::ReqwestExecutor;
     use crate::client::Client;
+    use crate::common::status::Status;
+    use crate::movie::Movie;
     use crate::prelude::Command;
 
     use super::MovieDetails;
@@ -89,6 +94,38 @@ mod tests {
         assert_eq!(result.inner.id, 550);
     }
 
+    #[test]
+    fn with_unknown_values() {
+        let mut value: serde_json::Value =
+            serde_json::from_str(include_str!("../../assets/movie-details.json")).unwrap();
+        value["status"] = "Announced".into();
+        let result: Movie = serde_json::from_value(value).unwrap();
+        assert_eq!(result.status, Status::Unknown("Announced".into()));
+    }
+
+    #[tokio::test]
+    async fn should_not_send_client_session() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .with_session(Some(Session::User("client-session".into())))
+            .build()
+            .unwrap();
+
+        let m = server
+            .mock("GET", "/movie/550")
+            .match_query(Matcher::Exact("api_key=secret".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/movie-details.json"))
+            .create_async()
+            .await;
+
+        MovieDetails::new(550).execute(&client).await.unwrap();
+        m.assert_async().await;
+    }
+
     #[tokio::test]
     async fn invalid_api_key() {
         let mut server = mockito::Server::new_async().await;
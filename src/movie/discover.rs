@@ -1,7 +1,89 @@
 use std::borrow::Cow;
+use std::fmt;
+
+use chrono::NaiveDate;
+
+use crate::common::filter::Filter;
+use crate::common::id::{CompanyId, KeywordId, PersonId};
+use crate::common::release_date::ReleaseDateKind;
+use crate::watch_provider::WatchMonetizationTypes;
 
 const PATH: &str = "/discover/movie";
 
+/// Order of the movies returned by the discover command.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum SortBy {
+    OriginalTitleAsc,
+    OriginalTitleDesc,
+    PopularityAsc,
+    #[default]
+    PopularityDesc,
+    PrimaryReleaseDateAsc,
+    PrimaryReleaseDateDesc,
+    RevenueAsc,
+    RevenueDesc,
+    TitleAsc,
+    TitleDesc,
+    VoteAverageAsc,
+    VoteAverageDesc,
+    VoteCountAsc,
+    VoteCountDesc,
+    /// Raw value, sent as is.
+    Other(String),
+}
+
+impl fmt::Display for SortBy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OriginalTitleAsc => write!(f, "original_title.asc"),
+            Self::OriginalTitleDesc => write!(f, "original_title.desc"),
+            Self::PopularityAsc => write!(f, "popularity.asc"),
+            Self::PopularityDesc => write!(f, "popularity.desc"),
+            Self::PrimaryReleaseDateAsc => write!(f, "primary_release_date.asc"),
+            Self::PrimaryReleaseDateDesc => write!(f, "primary_release_date.desc"),
+            Self::RevenueAsc => write!(f, "revenue.asc"),
+            Self::RevenueDesc => write!(f, "revenue.desc"),
+            Self::TitleAsc => write!(f, "title.asc"),
+            Self::TitleDesc => write!(f, "title.desc"),
+            Self::VoteAverageAsc => write!(f, "vote_average.asc"),
+            Self::VoteAverageDesc => write!(f, "vote_average.desc"),
+            Self::VoteCountAsc => write!(f, "vote_count.asc"),
+            Self::VoteCountDesc => write!(f, "vote_count.desc"),
+            Self::Other(value) => f.write_str(value),
+        }
+    }
+}
+
+/// Command to discover movies matching a set of filters
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use tmdb_api::prelude::Command;
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::common::filter::Filter;
+/// use tmdb_api::common::id::PersonId;
+/// use tmdb_api::common::release_date::ReleaseDateKind;
+/// use tmdb_api::movie::discover::MovieDiscover;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into());
+///     // movies with Brad Pitt or Edward Norton, released in theaters in 1999
+///     let cmd = MovieDiscover::new()
+///         .with_people(Some(Filter::any([287, 819].map(PersonId::from))))
+///         .with_release_type(Some(Filter::any([
+///             ReleaseDateKind::TheatricalLimited,
+///             ReleaseDateKind::Theatrical,
+///         ])))
+///         .with_primary_release_year(Some(1999));
+///     let result = cmd.execute(&client).await;
+///     match result {
+///         Ok(res) => println!("found: {:#?}", res),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
 #[derive(Clone, Debug, Default)]
 pub struct MovieDiscover {
     /// ISO 639-1 value to display translated data for the fields that support it.
@@ -10,6 +92,8 @@ pub struct MovieDiscover {
     pub page: Option<u32>,
     /// Whether to include adult (pornography) content in the results.
     pub include_adult: bool,
+    /// Whether to include the videos that are not movies, like concerts or making of.
+    pub include_video: bool,
     /// ISO 3166-1 code to filter release region. Must be uppercase.
     pub region: Option<String>,
     /// ISO 3166-1 code to filter release region. Must be uppercase.
@@ -18,29 +102,59 @@ pub struct MovieDiscover {
     pub with_origin_country: Option<String>,
     /// Language code. Must be lowercase.
     pub with_original_language: Option<String>,
-    /// Sort By
-    pub sort_by: Option<String>,
-    /// With watch monetization types
-    pub with_watch_monetization_types: Option<String>,
-    pub with_primary_release_date_gte: Option<String>,
+    pub sort_by: Option<SortBy>,
+    /// Monetization types, used with the watch region.
+    pub with_watch_monetization_types: Option<WatchMonetizationTypes>,
+    /// Country of the certifications, required by the certification filters.
+    pub certification_country: Option<String>,
+    pub certification: Option<String>,
+    pub certification_gte: Option<String>,
+    pub certification_lte: Option<String>,
+    pub with_primary_release_date_gte: Option<NaiveDate>,
+    pub with_primary_release_date_lte: Option<NaiveDate>,
+    pub primary_release_year: Option<u16>,
+    /// Release date of any kind, in the region when provided.
+    pub with_release_date_gte: Option<NaiveDate>,
+    /// Release date of any kind, in the region when provided.
+    pub with_release_date_lte: Option<NaiveDate>,
+    /// Kinds of release the release date filters apply to.
+    pub with_release_type: Option<Filter<ReleaseDateKind>>,
+    /// Year of any release of the movie.
+    pub year: Option<u16>,
     // vote_average.gte
     pub with_vote_average_gte: Option<f32>,
+    pub with_vote_average_lte: Option<f32>,
+    pub with_vote_count_gte: Option<u64>,
+    pub with_vote_count_lte: Option<u64>,
+    /// Runtime of the movie, in minutes.
+    pub with_runtime_gte: Option<u32>,
+    /// Runtime of the movie, in minutes.
+    pub with_runtime_lte: Option<u32>,
+    /// IDs of the people in the cast.
+    pub with_cast: Option<Filter<PersonId>>,
+    /// IDs of the people in the crew.
+    pub with_crew: Option<Filter<PersonId>>,
+    /// IDs of the people in the cast or in the crew.
+    pub with_people: Option<Filter<PersonId>>,
+    /// IDs of the production companies.
+    pub with_companies: Option<Filter<CompanyId>>,
+    /// IDs of the production companies.
+    pub without_companies: Option<Filter<CompanyId>>,
+    /// IDs of the genres.
+    pub with_genres: Option<Filter<u64>>,
+    /// IDs of the genres.
+    pub without_genres: Option<Filter<u64>>,
+    /// IDs of the keywords.
+    pub with_keywords: Option<Filter<KeywordId>>,
+    /// IDs of the keywords.
+    pub without_keywords: Option<Filter<KeywordId>>,
 }
 
 impl MovieDiscover {
     pub fn new() -> Self {
         Self {
-            language: None,
-            page: None,
-            include_adult: false,
-            region: None,
-            watch_region: None,
-            with_origin_country: None,
-            with_original_language: None,
-            sort_by: Some("popularity.desc".into()),
-            with_watch_monetization_types: None,
-            with_primary_release_date_gte: None,
-            with_vote_average_gte: None,
+            sort_by: Some(SortBy::default()),
+            ..Default::default()
         }
     }
 
@@ -59,6 +173,11 @@ impl MovieDiscover {
         self
     }
 
+    pub fn with_include_video(mut self, value: bool) -> Self {
+        self.include_video = value;
+        self
+    }
+
     pub fn with_region(mut self, value: Option<String>) -> Self {
         self.region = value;
         self
@@ -79,25 +198,145 @@ impl MovieDiscover {
         self
     }
 
-    pub fn with_sort_by(mut self, value: Option<String>) -> Self {
+    pub fn with_sort_by(mut self, value: Option<SortBy>) -> Self {
         self.sort_by = value;
         self
     }
 
-    pub fn with_watch_monetization_types(mut self, value: Option<String>) -> Self {
+    pub fn with_watch_monetization_types(mut self, value: Option<WatchMonetizationTypes>) -> Self {
         self.with_watch_monetization_types = value;
         self
     }
 
-    pub fn with_primary_release_date_gte(mut self, value: Option<String>) -> Self {
+    pub fn with_certification_country(mut self, value: Option<String>) -> Self {
+        self.certification_country = value;
+        self
+    }
+
+    pub fn with_certification(mut self, value: Option<String>) -> Self {
+        self.certification = value;
+        self
+    }
+
+    pub fn with_certification_gte(mut self, value: Option<String>) -> Self {
+        self.certification_gte = value;
+        self
+    }
+
+    pub fn with_certification_lte(mut self, value: Option<String>) -> Self {
+        self.certification_lte = value;
+        self
+    }
+
+    pub fn with_primary_release_date_gte(mut self, value: Option<NaiveDate>) -> Self {
         self.with_primary_release_date_gte = value;
         self
     }
 
+    pub fn with_primary_release_date_lte(mut self, value: Option<NaiveDate>) -> Self {
+        self.with_primary_release_date_lte = value;
+        self
+    }
+
+    pub fn with_primary_release_year(mut self, value: Option<u16>) -> Self {
+        self.primary_release_year = value;
+        self
+    }
+
+    pub fn with_release_date_gte(mut self, value: Option<NaiveDate>) -> Self {
+        self.with_release_date_gte = value;
+        self
+    }
+
+    pub fn with_release_date_lte(mut self, value: Option<NaiveDate>) -> Self {
+        self.with_release_date_lte = value;
+        self
+    }
+
+    pub fn with_release_type(mut self, value: Option<Filter<ReleaseDateKind>>) -> Self {
+        self.with_release_type = value;
+        self
+    }
+
+    pub fn with_year(mut self, value: Option<u16>) -> Self {
+        self.year = value;
+        self
+    }
+
     pub fn with_vote_average_gte(mut self, value: Option<f32>) -> Self {
         self.with_vote_average_gte = value;
         self
     }
+
+    pub fn with_vote_average_lte(mut self, value: Option<f32>) -> Self {
+        self.with_vote_average_lte = value;
+        self
+    }
+
+    pub fn with_vote_count_gte(mut self, value: Option<u64>) -> Self {
+        self.with_vote_count_gte = value;
+        self
+    }
+
+    pub fn with_vote_count_lte(mut self, value: Option<u64>) -> Self {
+        self.with_vote_count_lte = value;
+        self
+    }
+
+    pub fn with_runtime_gte(mut self, value: Option<u32>) -> Self {
+        self.with_runtime_gte = value;
+        self
+    }
+
+    pub fn with_runtime_lte(mut self, value: Option<u32>) -> Self {
+        self.with_runtime_lte = value;
+        self
+    }
+
+    pub fn with_cast(mut self, value: Option<Filter<PersonId>>) -> Self {
+        self.with_cast = value;
+        self
+    }
+
+    pub fn with_crew(mut self, value: Option<Filter<PersonId>>) -> Self {
+        self.with_crew = value;
+        self
+    }
+
+    pub fn with_people(mut self, value: Option<Filter<PersonId>>) -> Self {
+        self.with_people = value;
+        self
+    }
+
+    pub fn with_companies(mut self, value: Option<Filter<CompanyId>>) -> Self {
+        self.with_companies = value;
+        self
+    }
+
+    pub fn without_companies(mut self, value: Option<Filter<CompanyId>>) -> Self {
+        self.without_companies = value;
+        self
+    }
+
+    pub fn with_genres(mut self, value: Option<Filter<u64>>) -> Self {
+        self.with_genres = value;
+        self
+    }
+
+    pub fn without_genres(mut self, value: Option<Filter<u64>>) -> Self {
+        self.without_genres = value;
+        self
+    }
+
+    pub fn with_keywords(mut self, value: Option<Filter<KeywordId>>) -> Self {
+        self.with_keywords = value;
+        self
+    }
+
+    pub fn without_keywords(mut self, value: Option<Filter<KeywordId>>) -> Self {
+        self.without_keywords = value;
+        self
+    }
 }
 
 impl crate::prelude::Command for MovieDiscover {
@@ -119,6 +358,9 @@ impl crate::prelude::Command for MovieDiscover {
         if self.include_adult {
             res.push(("include_adult", Cow::Borrowed("true")));
         }
+        if self.include_video {
+            res.push(("include_video", Cow::Borrowed("true")));
+        }
         if let Some(region) = self.region.as_ref() {
             res.push(("region", Cow::Borrowed(region.as_str())));
         }
@@ -138,7 +380,7 @@ impl crate::prelude::Command for MovieDiscover {
             ));
         }
         if let Some(sort_by) = self.sort_by.as_ref() {
-            res.push(("sort_by", Cow::Borrowed(sort_by.as_str())));
+            res.push(("sort_by", Cow::Owned(sort_by.to_string())));
         }
         if let Some(with_watch_monetization_types) = self.with_watch_monetization_types.as_ref() {
             res.push((
@@ -146,17 +388,83 @@ impl crate::prelude::Command for MovieDiscover {
                 Cow::Owned(with_watch_monetization_types.to_string()),
             ));
         }
-        if let Some(with_primary_release_date_gte) = self.with_primary_release_date_gte.as_ref() {
-            res.push((
-                "primary_release_date.gte",
-                Cow::Owned(with_primary_release_date_gte.to_string()),
-            ));
+        if let Some(value) = self.certification_country.as_ref() {
+            res.push(("certification_country", Cow::Borrowed(value.as_str())));
         }
-        if let Some(with_vote_average_gte) = self.with_vote_average_gte {
-            res.push((
-                "vote_average.gte",
-                Cow::Owned(with_vote_average_gte.to_string()),
-            ));
+        if let Some(value) = self.certification.as_ref() {
+            res.push(("certification", Cow::Borrowed(value.as_str())));
+        }
+        if let Some(value) = self.certification_gte.as_ref() {
+            res.push(("certification.gte", Cow::Borrowed(value.as_str())));
+        }
+        if let Some(value) = self.certification_lte.as_ref() {
+            res.push(("certification.lte", Cow::Borrowed(value.as_str())));
+        }
+        if let Some(value) = self.with_primary_release_date_gte {
+            res.push(("primary_release_date.gte", Cow::Owned(value.to_string())));
+        }
+        if let Some(value) = self.with_primary_release_date_lte {
+            res.push(("primary_release_date.lte", Cow::Owned(value.to_string())));
+        }
+        if let Some(value) = self.primary_release_year {
+            res.push(("primary_release_year", Cow::Owned(value.to_string())));
+        }
+        if let Some(value) = self.with_release_date_gte {
+            res.push(("release_date.gte", Cow::Owned(value.to_string())));
+        }
+        if let Some(value) = self.with_release_date_lte {
+            res.push(("release_date.lte", Cow::Owned(value.to_string())));
+        }
+        if let Some(value) = self.with_release_type.as_ref() {
+            res.push(("with_release_type", Cow::Owned(value.to_string())));
+        }
+        if let Some(value) = self.year {
+            res.push(("year", Cow::Owned(value.to_string())));
+        }
+        if let Some(value) = self.with_vote_average_gte {
+            res.push(("vote_average.gte", Cow::Owned(value.to_string())));
+        }
+        if let Some(value) = self.with_vote_average_lte {
+            res.push(("vote_average.lte", Cow::Owned(value.to_string())));
+        }
+        if let Some(value) = self.with_vote_count_gte {
+            res.push(("vote_count.gte", Cow::Owned(value.to_string())));
+        }
+        if let Some(value) = self.with_vote_count_lte {
+            res.push(("vote_count.lte", Cow::Owned(value.to_string())));
+        }
+        if let Some(value) = self.with_runtime_gte {
+            res.push(("with_runtime.gte", Cow::Owned(value.to_string())));
+        }
+        if let Some(value) = self.with_runtime_lte {
+            res.push(("with_runtime.lte", Cow::Owned(value.to_string())));
+        }
+        if let Some(value) = self.with_cast.as_ref() {
+            res.push(("with_cast", Cow::Owned(value.to_string())));
+        }
+        if let Some(value) = self.with_crew.as_ref() {
+            res.push(("with_crew", Cow::Owned(value.to_string())));
+        }
+        if let Some(value) = self.with_people.as_ref() {
+            res.push(("with_people", Cow::Owned(value.to_string())));
+        }
+        if let Some(value) = self.with_companies.as_ref() {
+            res.push(("with_companies", Cow::Owned(value.to_string())));
+        }
+        if let Some(value) = self.without_companies.as_ref() {
+            res.push(("without_companies", Cow::Owned(value.to_string())));
+        }
+        if let Some(value) = self.with_genres.as_ref() {
+            res.push(("with_genres", Cow::Owned(value.to_string())));
+        }
+        if let Some(value) = self.without_genres.as_ref() {
+            res.push(("without_genres", Cow::Owned(value.to_string())));
+        }
+        if let Some(value) = self.with_keywords.as_ref() {
+            res.push(("with_keywords", Cow::Owned(value.to_string())));
+        }
+        if let Some(value) = self.without_keywords.as_ref() {
+            res.push(("without_keywords", Cow::Owned(value.to_string())));
         }
 
         res
@@ -165,10 +473,15 @@ impl crate::prelude::Command for MovieDiscover {
 
 #[cfg(test)]
 mod tests {
-    use super::MovieDiscover;
+    use super::{MovieDiscover, SortBy};
     use crate::client::reqwest::ReqwestExecutor;
     use crate::client::Client;
+    use crate::common::filter::Filter;
+    use crate::common::id::{KeywordId, PersonId};
+    use crate::common::release_date::ReleaseDateKind;
     use crate::prelude::Command;
+    use crate::watch_provider::{WatchMonetizationType, WatchMonetizationTypes};
+    use chrono::NaiveDate;
     use mockito::Matcher;
 
     #[tokio::test]
@@ -202,6 +515,83 @@ mod tests {
         assert_eq!(item.inner.title, "Le clitoris");
     }
 
+    #[tokio::test]
+    async fn should_send_movie_filters() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let cmd = MovieDiscover::new()
+            .with_cast(Some(Filter::all([287, 819].map(PersonId::from))))
+            .with_crew(Some(Filter::any([7467, 1254].map(PersonId::from))))
+            .with_genres(Some(Filter::any([18, 53])))
+            .without_keywords(Some(Filter::all([210024, 9715].map(KeywordId::from))))
+            .with_release_type(Some(Filter::any([
+                ReleaseDateKind::TheatricalLimited,
+                ReleaseDateKind::Theatrical,
+            ])))
+            .with_release_date_gte(NaiveDate::from_ymd_opt(1999, 1, 1))
+            .with_release_date_lte(NaiveDate::from_ymd_opt(1999, 12, 31))
+            .with_certification_country(Some("US".into()))
+            .with_certification_lte(Some("R".into()))
+            .with_runtime_gte(Some(90))
+            .with_vote_count_gte(Some(1000))
+            .with_sort_by(Some(SortBy::PrimaryReleaseDateDesc))
+            .with_watch_region(Some("FR".into()))
+            .with_watch_monetization_types(Some(WatchMonetizationTypes::from_iter([
+                WatchMonetizationType::Buy,
+                WatchMonetizationType::Flatrate,
+            ])));
+
+        let m = server
+            .mock("GET", super::PATH)
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("api_key".into(), "secret".into()),
+                Matcher::UrlEncoded("with_cast".into(), "287,819".into()),
+                Matcher::UrlEncoded("with_crew".into(), "7467|1254".into()),
+                Matcher::UrlEncoded("with_genres".into(), "18|53".into()),
+                Matcher::UrlEncoded("without_keywords".into(), "210024,9715".into()),
+                Matcher::UrlEncoded("with_release_type".into(), "2|3".into()),
+                Matcher::UrlEncoded("release_date.gte".into(), "1999-01-01".into()),
+                Matcher::UrlEncoded("release_date.lte".into(), "1999-12-31".into()),
+                Matcher::UrlEncoded("certification_country".into(), "US".into()),
+                Matcher::UrlEncoded("certification.lte".into(), "R".into()),
+                Matcher::UrlEncoded("with_runtime.gte".into(), "90".into()),
+                Matcher::UrlEncoded("vote_count.gte".into(), "1000".into()),
+                Matcher::UrlEncoded("sort_by".into(), "primary_release_date.desc".into()),
+                Matcher::UrlEncoded("watch_region".into(), "FR".into()),
+                Matcher::UrlEncoded(
+                    "with_watch_monetization_types".into(),
+                    "flatrate|buy".into(),
+                ),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/discover-movie.json"))
+            .create_async()
+            .await;
+        cmd.execute(&client).await.unwrap();
+        m.assert_async().await;
+    }
+
+    #[test]
+    fn should_sort_by_popularity_by_default() {
+        let cmd = MovieDiscover::new();
+        assert_eq!(cmd.sort_by.unwrap().to_string(), "popularity.desc");
+    }
+
+    #[test]
+    fn should_send_raw_values_as_is() {
+        assert_eq!(SortBy::Other("foo.asc".into()).to_string(), "foo.asc");
+        assert_eq!(
+            WatchMonetizationTypes::Other("flatrate,free".into()).to_string(),
+            "flatrate,free"
+        );
+    }
+
     #[tokio::test]
     async fn invalid_api_key() {
         let mut server = mockito::Server::new_async().await;
@@ -0,0 +1,507 @@
+use std::collections::HashSet;
+
+use chrono::{Days, NaiveDate};
+
+use crate::client::{Client, Executor};
+use crate::common::id::MovieId;
+use crate::common::PaginatedResult;
+use crate::prelude::Command;
+
+use super::discover::MovieDiscover;
+use super::MovieShort;
+
+/// Highest page TMDB accepts, the results after it can't be reached.
+pub const MAX_PAGES: u32 = 500;
+
+const MIN_VOTE_COUNT: u64 = 0;
+const MAX_VOTE_COUNT: u64 = u32::MAX as u64;
+
+fn min_date() -> NaiveDate {
+    NaiveDate::from_ymd_opt(1870, 1, 1).unwrap()
+}
+
+fn max_date() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2100, 12, 31).unwrap()
+}
+
+/// Part of the query, small enough to be fetched without reaching the page cap.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Window {
+    from: NaiveDate,
+    to: NaiveDate,
+    min_votes: u64,
+    max_votes: u64,
+}
+
+impl Window {
+    /// Splits the release date range in two, or the vote count range when it's a single day.
+    fn split(&self) -> Option<(Window, Window)> {
+        if self.from < self.to {
+            let days = (self.to - self.from).num_days() as u64;
+            let middle = self.from.checked_add_days(Days::new(days / 2))?;
+            let next = middle.succ_opt()?;
+            Some((
+                Window {
+                    to: middle,
+                    ..*self
+                },
+                Window {
+                    from: next,
+                    ..*self
+                },
+            ))
+        } else if self.min_votes < self.max_votes {
+            let middle = self.min_votes + (self.max_votes - self.min_votes) / 2;
+            Some((
+                Window {
+                    max_votes: middle,
+                    ..*self
+                },
+                Window {
+                    min_votes: middle + 1,
+                    ..*self
+                },
+            ))
+        } else {
+            None
+        }
+    }
+}
+
+/// Window still over the page cap that can't be split anymore, being a single day with a
+/// single vote count. Only its first pages were crawled.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TruncatedWindow {
+    pub primary_release_date: NaiveDate,
+    pub vote_count: u64,
+    /// Number of pages TMDB has for this window.
+    pub total_pages: u64,
+}
+
+fn total_pages<T>(page: &PaginatedResult<T>) -> u32 {
+    u32::try_from(page.total_pages).unwrap_or(u32::MAX)
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Cursor {
+    window: Window,
+    next_page: u32,
+    last_page: u32,
+}
+
+/// Crawls all the movies matching a discover command, beyond the 500 pages TMDB allows.
+///
+/// The query is split in primary release date windows, and then in vote count windows when a
+/// single day still has too many movies, until each window fits in the page cap.
+/// The movies are returned page by page, without duplicates. The movies without release date
+/// can't be reached by the date windows and are skipped.
+///
+/// A single day with a single vote count can't be split anymore. When such a window still has
+/// more pages than the cap, only its first pages are crawled and it's listed by
+/// [`MovieDiscoverCrawler::truncated`], so the caller knows some movies were not reached.
+///
+/// ```rust
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::movie::discover::MovieDiscover;
+/// use tmdb_api::movie::discover_crawler::MovieDiscoverCrawler;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into());
+///     let cmd = MovieDiscover::new().with_original_language(Some("fr".into()));
+///     let mut crawler = MovieDiscoverCrawler::new(cmd);
+///     loop {
+///         match crawler.next_page(&client).await {
+///             Ok(Some(movies)) => println!("found: {:#?}", movies),
+///             Ok(None) => break,
+///             Err(err) => {
+///                 eprintln!("error: {:?}", err);
+///                 break;
+///             }
+///         }
+///     }
+/// }
+/// ```
+#[derive(Debug)]
+pub struct MovieDiscoverCrawler {
+    command: MovieDiscover,
+    max_pages: u32,
+    windows: Vec<Window>,
+    cursor: Option<Cursor>,
+    seen: HashSet<MovieId>,
+    truncated: Vec<TruncatedWindow>,
+}
+
+impl MovieDiscoverCrawler {
+    /// Builds a crawler for the command. Its release date and vote count ranges, when set,
+    /// bound the windows.
+    pub fn new(command: MovieDiscover) -> Self {
+        let window = Window {
+            from: command
+                .with_primary_release_date_gte
+                .unwrap_or_else(min_date),
+            to: command
+                .with_primary_release_date_lte
+                .unwrap_or_else(max_date),
+            min_votes: command.with_vote_count_gte.unwrap_or(MIN_VOTE_COUNT),
+            max_votes: command.with_vote_count_lte.unwrap_or(MAX_VOTE_COUNT),
+        };
+        Self {
+            command,
+            max_pages: MAX_PAGES,
+            windows: vec![window],
+            cursor: None,
+            seen: HashSet::new(),
+            truncated: Vec::new(),
+        }
+    }
+
+    /// Changes the highest page that can be requested.
+    pub fn with_max_pages(mut self, value: u32) -> Self {
+        self.max_pages = value.max(1);
+        self
+    }
+
+    /// Windows that had more pages than the cap and couldn't be split, so only partially
+    /// crawled.
+    pub fn truncated(&self) -> &[TruncatedWindow] {
+        &self.truncated
+    }
+
+    fn command_for(&self, window: &Window, page: u32) -> MovieDiscover {
+        self.command
+            .clone()
+            .with_page(Some(page))
+            .with_primary_release_date_gte(Some(window.from))
+            .with_primary_release_date_lte(Some(window.to))
+            .with_vote_count_gte(Some(window.min_votes))
+            .with_vote_count_lte(Some(window.max_votes))
+    }
+
+    fn deduplicate(&mut self, results: Vec<MovieShort>) -> Vec<MovieShort> {
+        results
+            .into_iter()
+            .filter(|item| self.seen.insert(item.inner.id))
+            .collect()
+    }
+
+    /// Fetches the next page of movies that were not returned yet.
+    ///
+    /// Returns `None` once every window has been crawled.
+    /// After an error, the next call requests the same page again.
+    pub async fn next_page<E: Executor>(
+        &mut self,
+        client: &Client<E>,
+    ) -> Result<Option<Vec<MovieShort>>, crate::error::Error> {
+        // the cursor and the windows only move once a page is fetched, so that a failed
+        // request is sent again by the next call
+        loop {
+            if let Some(cursor) = self.cursor {
+                if cursor.next_page > cursor.last_page {
+                    self.cursor = None;
+                    continue;
+                }
+                let cmd = self.command_for(&cursor.window, cursor.next_page);
+                let page: PaginatedResult<MovieShort> = cmd.execute(client).await?;
+                self.cursor = Some(Cursor {
+                    next_page: cursor.next_page + 1,
+                    last_page: cursor.last_page.min(total_pages(&page)),
+                    ..cursor
+                });
+                let results = self.deduplicate(page.results);
+                if !results.is_empty() {
+                    return Ok(Some(results));
+                }
+                continue;
+            }
+
+            let Some(window) = self.windows.last().copied() else {
+                return Ok(None);
+            };
+            let page = self.command_for(&window, 1).execute(client).await?;
+            self.windows.pop();
+            if page.total_pages > self.max_pages as u64 {
+                if let Some((first, second)) = window.split() {
+                    self.windows.push(second);
+                    self.windows.push(first);
+                    continue;
+                }
+                self.truncated.push(TruncatedWindow {
+                    primary_release_date: window.from,
+                    vote_count: window.min_votes,
+                    total_pages: page.total_pages,
+                });
+            }
+            self.cursor = Some(Cursor {
+                window,
+                next_page: 2,
+                last_page: self.max_pages.min(total_pages(&page)),
+            });
+            let results = self.deduplicate(page.results);
+            if !results.is_empty() {
+                return Ok(Some(results));
+            }
+        }
+    }
+
+    /// Fetches all the remaining movies.
+    pub async fn collect<E: Executor>(
+        &mut self,
+        client: &Client<E>,
+    ) -> Result<Vec<MovieShort>, crate::error::Error> {
+        let mut res = Vec::new();
+        while let Some(items) = self.next_page(client).await? {
+            res.extend(items);
+        }
+        Ok(res)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use chrono::{Days, NaiveDate};
+    use mockito::Matcher;
+
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::movie::discover::MovieDiscover;
+
+    use super::{MovieDiscoverCrawler, TruncatedWindow, Window};
+
+    const PAGE_SIZE: usize = 20;
+
+    /// Movies of the fake server, 150 of them being released the same day.
+    fn dataset() -> Vec<(u64, NaiveDate, u64)> {
+        let start = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap();
+        let crowded = NaiveDate::from_ymd_opt(2020, 1, 1).unwrap();
+        (0..400)
+            .map(|id| {
+                if id < 150 {
+                    (id, crowded, id * 3)
+                } else {
+                    (id, start.checked_add_days(Days::new(id * 7)).unwrap(), 10)
+                }
+            })
+            .collect()
+    }
+
+    fn param<'a>(query: &'a str, name: &str) -> Option<&'a str> {
+        query
+            .split('&')
+            .filter_map(|pair| pair.split_once('='))
+            .find(|(key, _)| *key == name)
+            .map(|(_, value)| value)
+    }
+
+    /// Mimics the discover endpoint, filtering on the release date and vote count.
+    fn respond(dataset: Vec<(u64, NaiveDate, u64)>, query: &str) -> Vec<u8> {
+        let from: NaiveDate = param(query, "primary_release_date.gte")
+            .unwrap()
+            .parse()
+            .unwrap();
+        let to: NaiveDate = param(query, "primary_release_date.lte")
+            .unwrap()
+            .parse()
+            .unwrap();
+        let min_votes: u64 = param(query, "vote_count.gte").unwrap().parse().unwrap();
+        let max_votes: u64 = param(query, "vote_count.lte").unwrap().parse().unwrap();
+        let page: usize = param(query, "page").unwrap().parse().unwrap();
+
+        let matching: Vec<_> = dataset
+            .into_iter()
+            .filter(|(_, date, votes)| {
+                *date >= from && *date <= to && *votes >= min_votes && *votes <= max_votes
+            })
+            .collect();
+        let results: Vec<_> = matching
+            .iter()
+            .skip((page - 1) * PAGE_SIZE)
+            .take(PAGE_SIZE)
+            .map(|(id, date, votes)| {
+                serde_json::json!({
+                    "id": id,
+                    "title": format!("Movie {id}"),
+                    "original_title": format!("Movie {id}"),
+                    "original_language": "fr",
+                    "overview": "",
+                    "release_date": date.to_string(),
+                    "poster_path": null,
+                    "backdrop_path": null,
+                    "adult": false,
+                    "popularity": 1.0,
+                    "vote_count": votes,
+                    "vote_average": 5.0,
+                    "video": false,
+                    "genre_ids": [],
+                })
+            })
+            .collect();
+        serde_json::to_vec(&serde_json::json!({
+            "page": page,
+            "total_results": matching.len(),
+            "total_pages": matching.len().div_ceil(PAGE_SIZE),
+            "results": results,
+        }))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn should_crawl_everything_under_the_page_cap() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/discover/movie")
+            .match_query(Matcher::UrlEncoded(
+                "with_original_language".into(),
+                "fr".into(),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body_from_request(|req| {
+                let (_, query) = req.path_and_query().split_once('?').unwrap();
+                respond(dataset(), query)
+            })
+            .expect_at_least(1)
+            .create_async()
+            .await;
+        // the server refuses the pages after the 5th
+        let over_cap = server
+            .mock("GET", "/discover/movie")
+            .match_query(Matcher::Regex("(^|&)page=([6-9]|[1-9][0-9]+)(&|$)".into()))
+            .with_status(422)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/validation-error.json"))
+            .expect(0)
+            .create_async()
+            .await;
+
+        let cmd = MovieDiscover::new().with_original_language(Some("fr".into()));
+        let mut crawler = MovieDiscoverCrawler::new(cmd).with_max_pages(5);
+        let mut ids = HashSet::new();
+        while let Some(items) = crawler.next_page(&client).await.unwrap() {
+            for item in items {
+                assert!(ids.insert(item.inner.id), "duplicate {}", item.inner.id);
+            }
+        }
+        assert_eq!(ids.len(), 400);
+        assert!(crawler.truncated().is_empty());
+        over_cap.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn should_resume_after_a_failed_page() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/discover/movie")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body_from_request(|req| {
+                let (_, query) = req.path_and_query().split_once('?').unwrap();
+                respond(dataset(), query)
+            })
+            .create_async()
+            .await;
+        let failing = server
+            .mock("GET", "/discover/movie")
+            .match_query(Matcher::Regex("(^|&)page=2(&|$)".into()))
+            .with_status(500)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let mut crawler = MovieDiscoverCrawler::new(MovieDiscover::new()).with_max_pages(5);
+        let mut ids = HashSet::new();
+        loop {
+            match crawler.next_page(&client).await {
+                Ok(Some(items)) => ids.extend(items.into_iter().map(|item| item.inner.id)),
+                Ok(None) => break,
+                Err(_) => {
+                    failing.assert_async().await;
+                    failing.remove_async().await;
+                }
+            }
+        }
+        assert_eq!(ids.len(), 400);
+    }
+
+    #[tokio::test]
+    async fn should_report_windows_that_cannot_be_split() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let day = NaiveDate::from_ymd_opt(2020, 1, 1).unwrap();
+        let _m = server
+            .mock("GET", "/discover/movie")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body_from_request(move |req| {
+                let (_, query) = req.path_and_query().split_once('?').unwrap();
+                // 150 movies released the same day with the same vote count
+                respond((0..150).map(|id| (id, day, 10)).collect(), query)
+            })
+            .create_async()
+            .await;
+
+        let cmd = MovieDiscover::new()
+            .with_primary_release_date_gte(Some(day))
+            .with_primary_release_date_lte(Some(day));
+        let mut crawler = MovieDiscoverCrawler::new(cmd).with_max_pages(5);
+        let items = crawler.collect(&client).await.unwrap();
+        assert_eq!(items.len(), 100);
+        assert_eq!(
+            crawler.truncated(),
+            &[TruncatedWindow {
+                primary_release_date: day,
+                vote_count: 10,
+                total_pages: 8,
+            }]
+        );
+    }
+
+    #[test]
+    fn should_split_dates_before_votes() {
+        let day = NaiveDate::from_ymd_opt(2020, 1, 1).unwrap();
+        let window = Window {
+            from: day,
+            to: day.checked_add_days(Days::new(3)).unwrap(),
+            min_votes: 0,
+            max_votes: 10,
+        };
+        let (first, second) = window.split().unwrap();
+        assert_eq!(first.to, day.checked_add_days(Days::new(1)).unwrap());
+        assert_eq!(second.from, day.checked_add_days(Days::new(2)).unwrap());
+        assert_eq!(first.max_votes, 10);
+
+        let (first, second) = Window { to: day, ..window }.split().unwrap();
+        assert_eq!((first.min_votes, first.max_votes), (0, 5));
+        assert_eq!((second.min_votes, second.max_votes), (6, 10));
+
+        let single = Window {
+            to: day,
+            min_votes: 4,
+            max_votes: 4,
+            ..window
+        };
+        assert!(single.split().is_none());
+    }
+}
@@ -1,5 +1,7 @@
 use std::borrow::Cow;
 
+use crate::common::id::MovieId;
+
 /// Command to get similar movies to a movie
 ///
 /// ```rust
@@ -22,18 +24,20 @@ use std::borrow::Cow;
 #[derive(Clone, Debug, Default)]
 pub struct MovieExternalIds {
     /// ID of the movie
-    pub movie_id: u64,
+    pub movie_id: MovieId,
 }
 
 impl MovieExternalIds {
-    pub fn new(movie_id: u64) -> Self {
-        Self { movie_id }
+    pub fn new(movie_id: impl Into<MovieId>) -> Self {
+        Self {
+            movie_id: movie_id.into(),
+        }
     }
 }
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct MovieExternalIdsResult {
-    pub id: u64,
+    pub id: MovieId,
     #[serde(deserialize_with = "crate::util::empty_string::deserialize")]
     pub imdb_id: Option<String>,
     #[serde(deserialize_with = "crate::util::empty_string::deserialize")]
@@ -1,5 +1,6 @@
 use std::borrow::Cow;
 
+use crate::common::id::MovieId;
 use crate::common::image::Image;
 
 /// Get the images that belong to a movie.
@@ -24,15 +25,15 @@ use crate::common::image::Image;
 #[derive(Clone, Debug, Default)]
 pub struct MovieImages {
     /// ID of the movie
-    pub movie_id: u64,
+    pub movie_id: MovieId,
     /// ISO 639-1 value to display translated data for the fields that support it.
     pub language: Option<String>,
 }
 
 impl MovieImages {
-    pub fn new(movie_id: u64) -> Self {
+    pub fn new(movie_id: impl Into<MovieId>) -> Self {
         Self {
-            movie_id,
+            movie_id: movie_id.into(),
             language: None,
         }
     }
@@ -45,7 +46,7 @@ impl MovieImages {
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct MovieImagesResult {
-    pub id: u64,
+    pub id: MovieId,
     pub backdrops: Vec<Image>,
     pub posters: Vec<Image>,
     pub logos: Vec<Image>,
@@ -1,5 +1,6 @@
 use std::borrow::Cow;
 
+use crate::common::id::MovieId;
 use crate::common::keyword::Keyword;
 
 /// Get the keywords that have been added to a movie.
@@ -24,18 +25,20 @@ use crate::common::keyword::Keyword;
 #[derive(Clone, Debug, Default)]
 pub struct MovieKeywords {
     /// ID of the movie
-    pub movie_id: u64,
+    pub movie_id: MovieId,
 }
 
 impl MovieKeywords {
-    pub fn new(movie_id: u64) -> Self {
-        Self { movie_id }
+    pub fn new(movie_id: impl Into<MovieId>) -> Self {
+        Self {
+            movie_id: movie_id.into(),
+        }
     }
 }
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct MovieKeywordsResult {
-    pub id: u64,
+    pub id: MovieId,
     pub keywords: Vec<Keyword>,
 }
 
@@ -1,6 +1,8 @@
 use std::borrow::Cow;
 
+use crate::common::id::MovieId;
 use crate::common::PaginatedResult;
+use crate::list::ListShort;
 
 /// Get a list of lists that this movie belongs to.
 ///
@@ -24,7 +26,7 @@ use crate::common::PaginatedResult;
 #[derive(Clone, Debug, Default)]
 pub struct MovieLists {
     /// ID of the movie.
-    pub movie_id: u64,
+    pub movie_id: MovieId,
     /// ISO 639-1 value to display translated data for the fields that support it.
     pub language: Option<String>,
     /// Specify which page to query.
@@ -32,9 +34,9 @@ pub struct MovieLists {
 }
 
 impl MovieLists {
-    pub fn new(movie_id: u64) -> Self {
+    pub fn new(movie_id: impl Into<MovieId>) -> Self {
         Self {
-            movie_id,
+            movie_id: movie_id.into(),
             language: None,
             page: None,
         }
@@ -51,21 +53,11 @@ impl MovieLists {
     }
 }
 
-#[derive(Debug, Deserialize, Serialize)]
-pub struct MovieList {
-    pub id: u64,
-    pub name: String,
-    #[serde(deserialize_with = "crate::util::empty_string::deserialize")]
-    pub description: Option<String>,
-    pub list_type: String,
-    pub poster_path: Option<String>,
-    pub iso_639_1: String,
-    pub item_count: u64,
-    pub favorite_count: u64,
-}
+#[deprecated = "Use list::ListShort instead."]
+pub type MovieList = ListShort;
 
 impl crate::prelude::Command for MovieLists {
-    type Output = PaginatedResult<MovieList>;
+    type Output = PaginatedResult<ListShort>;
 
     fn path(&self) -> Cow<'static, str> {
         Cow::Owned(format!("/movie/{}/lists", self.movie_id))
@@ -1,14 +1,22 @@
 #[cfg(feature = "commands")]
+pub mod account_states;
+#[cfg(feature = "commands")]
+pub mod add_rating;
+#[cfg(feature = "commands")]
 pub mod alternative_titles;
 #[cfg(feature = "commands")]
 pub mod changes;
 #[cfg(feature = "commands")]
 pub mod credits;
 #[cfg(feature = "commands")]
+pub mod delete_rating;
+#[cfg(feature = "commands")]
 pub mod details;
 #[cfg(feature = "commands")]
 pub mod discover;
 #[cfg(feature = "commands")]
+pub mod discover_crawler;
+#[cfg(feature = "commands")]
 pub mod external_ids;
 #[cfg(feature = "commands")]
 pub mod images;
@@ -47,6 +55,7 @@ pub mod watch_providers;
 
 use crate::collection::CollectionBase;
 use crate::common::country::Country;
+use crate::common::id::MovieId;
 use crate::common::language::Language;
 use crate::common::status::Status;
 use crate::company::CompanyShort;
@@ -54,7 +63,7 @@ use crate::genre::Genre;
 
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct MovieBase {
-    pub id: u64,
+    pub id: MovieId,
     pub title: String,
     #[serde(default)]
     pub origin_country: Vec<String>,
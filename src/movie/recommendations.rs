@@ -1,5 +1,6 @@
 use std::borrow::Cow;
 
+use crate::common::id::MovieId;
 use crate::common::PaginatedResult;
 
 /// Get a list of recommended movies for a movie.
@@ -24,7 +25,7 @@ use crate::common::PaginatedResult;
 #[derive(Clone, Debug, Default)]
 pub struct MovieRecommendations {
     /// ID of the movie.
-    pub movie_id: u64,
+    pub movie_id: MovieId,
     /// ISO 639-1 value to display translated data for the fields that support it.
     pub language: Option<String>,
     /// Specify which page to query.
@@ -32,9 +33,9 @@ pub struct MovieRecommendations {
 }
 
 impl MovieRecommendations {
-    pub fn new(movie_id: u64) -> Self {
+    pub fn new(movie_id: impl Into<MovieId>) -> Self {
         Self {
-            movie_id,
+            movie_id: movie_id.into(),
             language: None,
             page: None,
         }
@@ -1,5 +1,6 @@
 use std::borrow::Cow;
 
+use crate::common::id::MovieId;
 use crate::common::release_date::LocatedReleaseDates;
 
 /// Get the release date along with the certification for a movie.
@@ -24,18 +25,20 @@ use crate::common::release_date::LocatedReleaseDates;
 #[derive(Clone, Debug, Default)]
 pub struct MovieReleaseDates {
     /// ID of the movie.
-    pub movie_id: u64,
+    pub movie_id: MovieId,
 }
 
 impl MovieReleaseDates {
-    pub fn new(movie_id: u64) -> Self {
-        Self { movie_id }
+    pub fn new(movie_id: impl Into<MovieId>) -> Self {
+        Self {
+            movie_id: movie_id.into(),
+        }
     }
 }
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct MovieReleaseDatesResult {
-    pub id: u64,
+    pub id: MovieId,
     pub results: Vec<LocatedReleaseDates>,
 }
 
@@ -53,9 +56,10 @@ impl crate::prelude::Command for MovieReleaseDates {
 
 #[cfg(test)]
 mod tests {
-    use super::MovieReleaseDates;
+    use super::{MovieReleaseDates, MovieReleaseDatesResult};
     use crate::client::reqwest::ReqwestExecutor;
     use crate::client::Client;
+    use crate::common::release_date::ReleaseDateKind;
     use crate::prelude::Command;
     use mockito::Matcher;
 
@@ -82,6 +86,17 @@ mod tests {
         assert!(!result.results.is_empty());
     }
 
+    #[test]
+    fn with_unknown_values() {
+        let mut value: serde_json::Value =
+            serde_json::from_str(include_str!("../../assets/movie-release-dates.json")).unwrap();
+        value["results"][0]["release_dates"][0]["type"] = 7.into();
+        let result: MovieReleaseDatesResult = serde_json::from_value(value).unwrap();
+        let item = result.results[0].release_dates.first().unwrap();
+        assert_eq!(item.kind, ReleaseDateKind::Unknown(7));
+        assert_eq!(item.kind.to_string(), "7");
+    }
+
     #[tokio::test]
     async fn invalid_api_key() {
         let mut server = mockito::Server::new_async().await;
@@ -1,7 +1,11 @@
 use std::borrow::Cow;
 
+use crate::common::id::MovieId;
+use crate::common::review::Review;
 use crate::common::PaginatedResult;
 
+pub use crate::common::review::AuthorDetails;
+
 /// Get the release date along with the certification for a movie.
 ///
 /// ```rust
@@ -24,36 +28,22 @@ use crate::common::PaginatedResult;
 #[derive(Clone, Debug, Default)]
 pub struct MovieReviews {
     /// ID of the movie.
-    pub movie_id: u64,
+    pub movie_id: MovieId,
 }
 
 impl MovieReviews {
-    pub fn new(movie_id: u64) -> Self {
-        Self { movie_id }
+    pub fn new(movie_id: impl Into<MovieId>) -> Self {
+        Self {
+            movie_id: movie_id.into(),
+        }
     }
 }
 
-#[derive(Debug, Deserialize, Serialize)]
-pub struct AuthorDetails {
-    pub name: String,
-    pub username: String,
-    pub avatar_path: Option<String>,
-    pub rating: Option<f32>,
-}
-
-#[derive(Debug, Deserialize, Serialize)]
-pub struct MovieReview {
-    pub id: String,
-    pub author: String,
-    pub author_details: AuthorDetails,
-    pub content: String,
-    pub url: String,
-    pub created_at: chrono::DateTime<chrono::Utc>,
-    pub updated_at: chrono::DateTime<chrono::Utc>,
-}
+#[deprecated = "Use common::review::Review instead."]
+pub type MovieReview = Review;
 
 impl crate::prelude::Command for MovieReviews {
-    type Output = PaginatedResult<MovieReview>;
+    type Output = PaginatedResult<Review>;
 
     fn path(&self) -> Cow<'static, str> {
         Cow::Owned(format!("/movie/{}/reviews", self.movie_id))
@@ -1,5 +1,7 @@
 use std::borrow::Cow;
 
+use crate::common::id::MovieId;
+
 /// Command to get similar movies to a movie
 ///
 /// ```rust
@@ -22,7 +24,7 @@ use std::borrow::Cow;
 #[derive(Clone, Debug, Default)]
 pub struct GetSimilarMovies {
     /// ID of the movie
-    pub movie_id: u64,
+    pub movie_id: MovieId,
     /// ISO 639-1 value to display translated data for the fields that support it.
     pub language: Option<String>,
     /// Which page to query.
@@ -30,9 +32,9 @@ pub struct GetSimilarMovies {
 }
 
 impl GetSimilarMovies {
-    pub fn new(movie_id: u64) -> Self {
+    pub fn new(movie_id: impl Into<MovieId>) -> Self {
         Self {
-            movie_id,
+            movie_id: movie_id.into(),
             language: None,
             page: None,
         }
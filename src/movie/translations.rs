@@ -1,5 +1,7 @@
 use std::borrow::Cow;
 
+use crate::common::id::MovieId;
+
 /// Get a list of translations that have been created for a movie.
 ///
 /// ```rust
@@ -22,12 +24,14 @@ use std::borrow::Cow;
 #[derive(Clone, Debug, Default)]
 pub struct MovieTranslations {
     /// ID of the movie.
-    pub movie_id: u64,
+    pub movie_id: MovieId,
 }
 
 impl MovieTranslations {
-    pub fn new(movie_id: u64) -> Self {
-        Self { movie_id }
+    pub fn new(movie_id: impl Into<MovieId>) -> Self {
+        Self {
+            movie_id: movie_id.into(),
+        }
     }
 }
 
@@ -54,7 +58,7 @@ pub struct Translation {
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct MovieTranslationsResult {
-    pub id: u64,
+    pub id: MovieId,
     pub translations: Vec<Translation>,
 }
 
@@ -1,5 +1,5 @@
-use crate::common::PaginatedResult;
-use std::{borrow::Cow, fmt};
+use crate::common::{PaginatedResult, TimeWindow};
+use std::borrow::Cow;
 
 /// Get a list of the current trending movies on TMDB. This list updates daily.
 ///
@@ -26,24 +26,11 @@ pub struct MovieTrending {
     /// Specify which page to query.
     pub page: Option<u32>,
     /// Time window
-    pub time_window: MovieTrendingTimeWindow,
+    pub time_window: TimeWindow,
 }
 
-#[derive(Clone, Debug, Default)]
-pub enum MovieTrendingTimeWindow {
-    #[default]
-    Day,
-    Week,
-}
-
-impl fmt::Display for MovieTrendingTimeWindow {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            Self::Day => write!(f, "day"),
-            Self::Week => write!(f, "week"),
-        }
-    }
-}
+#[deprecated = "Use common::TimeWindow instead."]
+pub type MovieTrendingTimeWindow = TimeWindow;
 
 impl MovieTrending {
     pub fn with_language(mut self, value: Option<String>) -> Self {
@@ -56,7 +43,7 @@ impl MovieTrending {
         self
     }
 
-    pub fn with_time_window(mut self, value: MovieTrendingTimeWindow) -> Self {
+    pub fn with_time_window(mut self, value: TimeWindow) -> Self {
         self.time_window = value;
         self
     }
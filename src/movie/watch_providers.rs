@@ -1,5 +1,6 @@
 use std::borrow::Cow;
 
+use crate::common::id::MovieId;
 use crate::watch_provider::WatchProviderResult;
 
 /// Get a list of watch providers for a movie.
@@ -24,12 +25,14 @@ use crate::watch_provider::WatchProviderResult;
 #[derive(Clone, Debug, Default)]
 pub struct MovieWatchProviders {
     /// ID of the movie.
-    pub movie_id: u64,
+    pub movie_id: MovieId,
 }
 
 impl MovieWatchProviders {
-    pub fn new(movie_id: u64) -> Self {
-        Self { movie_id }
+    pub fn new(movie_id: impl Into<MovieId>) -> Self {
+        Self {
+            movie_id: movie_id.into(),
+        }
     }
 }
 
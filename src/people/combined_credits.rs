@@ -0,0 +1,203 @@
+use std::borrow::Cow;
+
+use crate::common::id::PersonId;
+
+use super::{CombinedCast, CombinedCrew, PersonCredits};
+
+/// Command to get the movie and tv show credits of a person
+///
+/// ```rust
+/// use tmdb_api::prelude::Command;
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::people::combined_credits::PersonCombinedCredits;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into());
+///     let cmd = PersonCombinedCredits::new(287);
+///     let result = cmd.execute(&client).await;
+///     match result {
+///         Ok(res) => println!("found: {:#?}", res),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct PersonCombinedCredits {
+    /// ID of the person
+    pub person_id: PersonId,
+    /// ISO 639-1 value to display translated data for the fields that support it.
+    pub language: Option<String>,
+}
+
+impl PersonCombinedCredits {
+    pub fn new(person_id: impl Into<PersonId>) -> Self {
+        Self {
+            person_id: person_id.into(),
+            language: None,
+        }
+    }
+
+    pub fn with_language(mut self, value: Option<String>) -> Self {
+        self.language = value;
+        self
+    }
+}
+
+impl crate::prelude::Command for PersonCombinedCredits {
+    type Output = PersonCredits<CombinedCast, CombinedCrew>;
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Owned(format!("/person/{}/combined_credits", self.person_id))
+    }
+
+    fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
+        if let Some(language) = self.language.as_ref() {
+            vec![("language", Cow::Borrowed(language.as_str()))]
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::people::{CombinedCast, CombinedCrew, PersonCredits};
+    use crate::prelude::Command;
+
+    use super::PersonCombinedCredits;
+
+    #[tokio::test]
+    async fn it_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/person/287/combined_credits")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/person-combined-credits.json"))
+            .create_async()
+            .await;
+
+        let result = PersonCombinedCredits::new(287)
+            .execute(&client)
+            .await
+            .unwrap();
+        assert_eq!(result.id, 287);
+        assert!(
+            matches!(result.cast.first(), Some(CombinedCast::Movie(item)) if item.inner.inner.id == 550)
+        );
+        assert!(
+            matches!(result.cast.last(), Some(CombinedCast::Tv(item)) if item.inner.inner.id == 1668)
+        );
+        assert_eq!(result.cast.last().unwrap().character(), "Will Colbert");
+        assert!(
+            matches!(result.crew.last(), Some(CombinedCrew::Tv(item)) if item.job == "Executive Producer")
+        );
+    }
+
+    #[test]
+    fn with_unknown_values() {
+        let mut value: serde_json::Value =
+            serde_json::from_str(include_str!("../../assets/person-combined-credits.json"))
+                .unwrap();
+        value["cast"][0]["media_type"] = "podcast".into();
+        value["crew"][0]["media_type"] = "podcast".into();
+        let result: PersonCredits<CombinedCast, CombinedCrew> =
+            serde_json::from_value(value).unwrap();
+        let item = result.cast.first().unwrap();
+        assert!(
+            matches!(item, CombinedCast::Unknown(item) if item.inner.media_type == "podcast" && item.inner.id == 550)
+        );
+        assert_eq!(item.character(), "Tyler Durden");
+        assert!(matches!(
+            result.crew.first(),
+            Some(CombinedCrew::Unknown(_))
+        ));
+        assert!(matches!(result.cast.last(), Some(CombinedCast::Tv(_))));
+    }
+
+    #[tokio::test]
+    async fn invalid_api_key() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/person/287/combined_credits")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(401)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/invalid-api-key.json"))
+            .create_async()
+            .await;
+
+        let err = PersonCombinedCredits::new(287)
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 7);
+    }
+
+    #[tokio::test]
+    async fn resource_not_found() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/person/287/combined_credits")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/resource-not-found.json"))
+            .create_async()
+            .await;
+
+        let err = PersonCombinedCredits::new(287)
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 34);
+    }
+}
+
+#[cfg(all(test, feature = "integration"))]
+mod integration_tests {
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::prelude::Command;
+
+    use super::PersonCombinedCredits;
+
+    #[tokio::test]
+    async fn execute() {
+        let secret = std::env::var("TMDB_TOKEN_V3").unwrap();
+        let client = Client::<ReqwestExecutor>::new(secret);
+
+        let result = PersonCombinedCredits::new(287)
+            .execute(&client)
+            .await
+            .unwrap();
+        assert_eq!(result.id, 287);
+    }
+}
@@ -1,5 +1,7 @@
 use std::borrow::Cow;
 
+use crate::common::id::PersonId;
+
 /// Command to get the details of a person
 ///
 /// ```rust
@@ -22,15 +24,15 @@ use std::borrow::Cow;
 #[derive(Clone, Debug, Default)]
 pub struct PersonDetails {
     /// ID of the person
-    pub person_id: u64,
+    pub person_id: PersonId,
     /// ISO 639-1 value to display translated data for the fields that support it.
     pub language: Option<String>,
 }
 
 impl PersonDetails {
-    pub fn new(person_id: u64) -> Self {
+    pub fn new(person_id: impl Into<PersonId>) -> Self {
         Self {
-            person_id,
+            person_id: person_id.into(),
             language: None,
         }
     }
@@ -63,6 +65,8 @@ mod tests {
 
     use crate::client::reqwest::ReqwestExecutor;
     use crate::client::Client;
+    use crate::common::department::Department;
+    use crate::common::gender::Gender;
     use crate::prelude::Command;
 
     use super::PersonDetails;
@@ -87,6 +91,8 @@ mod tests {
 
         let result = PersonDetails::new(287).execute(&client).await.unwrap();
         assert_eq!(result.inner.id, 287);
+        assert_eq!(result.inner.gender, Gender::Male);
+        assert_eq!(result.known_for_department, Some(Department::Acting));
     }
 
     #[tokio::test]
@@ -0,0 +1,179 @@
+use std::borrow::Cow;
+
+use crate::common::id::PersonId;
+
+/// Get the external ids (IMDb, Wikidata, social networks...) of a person.
+///
+/// ```rust
+/// use tmdb_api::prelude::Command;
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::people::external_ids::PersonExternalIds;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into());
+///     let cmd = PersonExternalIds::new(287);
+///     let result = cmd.execute(&client).await;
+///     match result {
+///         Ok(res) => println!("found: {:#?}", res),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct PersonExternalIds {
+    /// ID of the person
+    pub person_id: PersonId,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PersonExternalIdsResult {
+    pub id: PersonId,
+    #[serde(default, deserialize_with = "crate::util::empty_string::deserialize")]
+    pub freebase_mid: Option<String>,
+    #[serde(default, deserialize_with = "crate::util::empty_string::deserialize")]
+    pub freebase_id: Option<String>,
+    #[serde(default, deserialize_with = "crate::util::empty_string::deserialize")]
+    pub imdb_id: Option<String>,
+    #[serde(default)]
+    pub tvrage_id: Option<u64>,
+    #[serde(default, deserialize_with = "crate::util::empty_string::deserialize")]
+    pub wikidata_id: Option<String>,
+    #[serde(default, deserialize_with = "crate::util::empty_string::deserialize")]
+    pub facebook_id: Option<String>,
+    #[serde(default, deserialize_with = "crate::util::empty_string::deserialize")]
+    pub instagram_id: Option<String>,
+    #[serde(default, deserialize_with = "crate::util::empty_string::deserialize")]
+    pub tiktok_id: Option<String>,
+    #[serde(default, deserialize_with = "crate::util::empty_string::deserialize")]
+    pub twitter_id: Option<String>,
+    #[serde(default, deserialize_with = "crate::util::empty_string::deserialize")]
+    pub youtube_id: Option<String>,
+}
+
+impl PersonExternalIds {
+    pub fn new(person_id: impl Into<PersonId>) -> Self {
+        Self {
+            person_id: person_id.into(),
+        }
+    }
+}
+
+impl crate::prelude::Command for PersonExternalIds {
+    type Output = PersonExternalIdsResult;
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Owned(format!("/person/{}/external_ids", self.person_id))
+    }
+
+    fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
+        Vec::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::prelude::Command;
+
+    use super::PersonExternalIds;
+
+    #[tokio::test]
+    async fn it_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/person/287/external_ids")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/person-external-ids.json"))
+            .create_async()
+            .await;
+
+        let result = PersonExternalIds::new(287).execute(&client).await.unwrap();
+        assert_eq!(result.id, 287);
+        assert_eq!(result.imdb_id.as_deref(), Some("nm0000093"));
+        assert_eq!(result.wikidata_id.as_deref(), Some("Q35332"));
+        assert_eq!(result.facebook_id, None);
+    }
+
+    #[tokio::test]
+    async fn invalid_api_key() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/person/287/external_ids")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(401)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/invalid-api-key.json"))
+            .create_async()
+            .await;
+
+        let err = PersonExternalIds::new(287)
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 7);
+    }
+
+    #[tokio::test]
+    async fn resource_not_found() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/person/287/external_ids")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/resource-not-found.json"))
+            .create_async()
+            .await;
+
+        let err = PersonExternalIds::new(287)
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 34);
+    }
+}
+
+#[cfg(all(test, feature = "integration"))]
+mod integration_tests {
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::prelude::Command;
+
+    use super::PersonExternalIds;
+
+    #[tokio::test]
+    async fn execute() {
+        let secret = std::env::var("TMDB_TOKEN_V3").unwrap();
+        let client = Client::<ReqwestExecutor>::new(secret);
+
+        let result = PersonExternalIds::new(287).execute(&client).await.unwrap();
+        assert_eq!(result.id, 287);
+    }
+}
@@ -1,12 +1,46 @@
 #[cfg(feature = "commands")]
+pub mod combined_credits;
+#[cfg(feature = "commands")]
 pub mod details;
+#[cfg(feature = "commands")]
+pub mod external_ids;
+#[cfg(feature = "commands")]
+pub mod images;
+#[cfg(feature = "commands")]
+pub mod latest;
+#[cfg(feature = "commands")]
+pub mod movie_credits;
+#[cfg(feature = "commands")]
+pub mod popular;
+#[cfg(feature = "commands")]
+pub mod search;
+#[cfg(feature = "commands")]
+pub mod tagged_images;
+#[cfg(feature = "commands")]
+pub mod translations;
+#[cfg(feature = "commands")]
+pub mod trending;
+#[cfg(feature = "commands")]
+pub mod tv_credits;
+
+use std::collections::BTreeMap;
+
+pub use crate::common::credits::CrewCredit;
+use crate::common::department::Department;
+use crate::common::gender::Gender;
+use crate::common::id::PersonId;
+use crate::common::media::UnknownMedia;
+use crate::movie::MovieShort;
+use crate::tvshow::TVShowShort;
+use crate::util::tagged;
 
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct PersonShort {
-    pub id: u64,
+    pub id: PersonId,
     pub credit_id: Option<String>,
     pub name: String,
-    pub gender: Option<u64>,
+    #[serde(default)]
+    pub gender: Gender,
     pub profile_path: Option<String>,
 }
 
@@ -24,8 +58,371 @@ pub struct Person {
     pub deathday: Option<chrono::NaiveDate>,
     pub homepage: Option<String>,
     pub imdb_id: Option<String>,
-    pub known_for_department: Option<String>,
+    pub known_for_department: Option<Department>,
     pub popularity: f64,
     pub place_of_birth: Option<String>,
     pub profile_path: Option<String>,
 }
+
+/// Movie or tv show a person is known for.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "media_type", rename_all = "lowercase")]
+pub enum KnownFor {
+    Movie(MovieShort),
+    Tv(TVShowShort),
+    /// Media type not known by this crate.
+    #[serde(untagged)]
+    Unknown(UnknownMedia),
+}
+
+impl<'de> serde::Deserialize<'de> for KnownFor {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (tag, value) = tagged::deserialize(deserializer, "media_type")?;
+        match tag.as_deref() {
+            Some("movie") => tagged::from_value(value).map(Self::Movie),
+            Some("tv") => tagged::from_value(value).map(Self::Tv),
+            _ => tagged::from_value(value).map(Self::Unknown),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct PersonWithKnownFor {
+    #[serde(flatten)]
+    pub inner: PersonShort,
+    #[serde(default)]
+    pub adult: bool,
+    pub known_for_department: Option<Department>,
+    #[serde(default)]
+    pub original_name: Option<String>,
+    pub popularity: f64,
+    #[serde(default)]
+    pub known_for: Vec<KnownFor>,
+}
+
+/// Something a person has been credited on, that can be placed in time.
+pub trait CreditedMedia {
+    /// Release date for movies, first air date for tv shows.
+    fn release_date(&self) -> Option<chrono::NaiveDate>;
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct MovieCast {
+    #[serde(flatten)]
+    pub inner: MovieShort,
+    pub credit_id: String,
+    #[serde(default)]
+    pub character: String,
+    pub order: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct MovieCrew {
+    #[serde(flatten)]
+    pub inner: MovieShort,
+    pub credit_id: String,
+    pub department: Department,
+    pub job: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct TVShowCast {
+    #[serde(flatten)]
+    pub inner: TVShowShort,
+    pub credit_id: String,
+    #[serde(default)]
+    pub character: String,
+    #[serde(default)]
+    pub episode_count: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct TVShowCrew {
+    #[serde(flatten)]
+    pub inner: TVShowShort,
+    pub credit_id: String,
+    pub department: Department,
+    pub job: String,
+    #[serde(default)]
+    pub episode_count: u64,
+}
+
+/// Cast credit on a media type not known by this crate.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct UnknownCast {
+    #[serde(flatten)]
+    pub inner: UnknownMedia,
+    pub credit_id: String,
+    #[serde(default)]
+    pub character: String,
+}
+
+/// Crew credit on a media type not known by this crate.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct UnknownCrew {
+    #[serde(flatten)]
+    pub inner: UnknownMedia,
+    pub credit_id: String,
+    pub department: Department,
+    pub job: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "media_type", rename_all = "lowercase")]
+pub enum CombinedCast {
+    Movie(MovieCast),
+    Tv(TVShowCast),
+    #[serde(untagged)]
+    Unknown(UnknownCast),
+}
+
+impl<'de> serde::Deserialize<'de> for CombinedCast {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (tag, value) = tagged::deserialize(deserializer, "media_type")?;
+        match tag.as_deref() {
+            Some("movie") => tagged::from_value(value).map(Self::Movie),
+            Some("tv") => tagged::from_value(value).map(Self::Tv),
+            _ => tagged::from_value(value).map(Self::Unknown),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "media_type", rename_all = "lowercase")]
+pub enum CombinedCrew {
+    Movie(MovieCrew),
+    Tv(TVShowCrew),
+    #[serde(untagged)]
+    Unknown(UnknownCrew),
+}
+
+impl<'de> serde::Deserialize<'de> for CombinedCrew {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (tag, value) = tagged::deserialize(deserializer, "media_type")?;
+        match tag.as_deref() {
+            Some("movie") => tagged::from_value(value).map(Self::Movie),
+            Some("tv") => tagged::from_value(value).map(Self::Tv),
+            _ => tagged::from_value(value).map(Self::Unknown),
+        }
+    }
+}
+
+impl CombinedCast {
+    pub fn credit_id(&self) -> &str {
+        match self {
+            Self::Movie(inner) => inner.credit_id.as_str(),
+            Self::Tv(inner) => inner.credit_id.as_str(),
+            Self::Unknown(inner) => inner.credit_id.as_str(),
+        }
+    }
+
+    pub fn character(&self) -> &str {
+        match self {
+            Self::Movie(inner) => inner.character.as_str(),
+            Self::Tv(inner) => inner.character.as_str(),
+            Self::Unknown(inner) => inner.character.as_str(),
+        }
+    }
+}
+
+impl CombinedCrew {
+    pub fn credit_id(&self) -> &str {
+        match self {
+            Self::Movie(inner) => inner.credit_id.as_str(),
+            Self::Tv(inner) => inner.credit_id.as_str(),
+            Self::Unknown(inner) => inner.credit_id.as_str(),
+        }
+    }
+
+    pub fn job(&self) -> &str {
+        match self {
+            Self::Movie(inner) => inner.job.as_str(),
+            Self::Tv(inner) => inner.job.as_str(),
+            Self::Unknown(inner) => inner.job.as_str(),
+        }
+    }
+}
+
+impl CreditedMedia for MovieCast {
+    fn release_date(&self) -> Option<chrono::NaiveDate> {
+        self.inner.inner.release_date
+    }
+}
+
+impl CreditedMedia for MovieCrew {
+    fn release_date(&self) -> Option<chrono::NaiveDate> {
+        self.inner.inner.release_date
+    }
+}
+
+impl CreditedMedia for TVShowCast {
+    fn release_date(&self) -> Option<chrono::NaiveDate> {
+        self.inner.inner.first_air_date
+    }
+}
+
+impl CreditedMedia for TVShowCrew {
+    fn release_date(&self) -> Option<chrono::NaiveDate> {
+        self.inner.inner.first_air_date
+    }
+}
+
+impl CreditedMedia for CombinedCast {
+    fn release_date(&self) -> Option<chrono::NaiveDate> {
+        match self {
+            Self::Movie(inner) => inner.release_date(),
+            Self::Tv(inner) => inner.release_date(),
+            Self::Unknown(_) => None,
+        }
+    }
+}
+
+impl CreditedMedia for CombinedCrew {
+    fn release_date(&self) -> Option<chrono::NaiveDate> {
+        match self {
+            Self::Movie(inner) => inner.release_date(),
+            Self::Tv(inner) => inner.release_date(),
+            Self::Unknown(_) => None,
+        }
+    }
+}
+
+impl CrewCredit for MovieCrew {
+    fn department(&self) -> &Department {
+        &self.department
+    }
+
+    fn has_job<F: Fn(&str) -> bool>(&self, predicate: F) -> bool {
+        predicate(self.job.as_str())
+    }
+}
+
+impl CrewCredit for TVShowCrew {
+    fn department(&self) -> &Department {
+        &self.department
+    }
+
+    fn has_job<F: Fn(&str) -> bool>(&self, predicate: F) -> bool {
+        predicate(self.job.as_str())
+    }
+}
+
+impl CrewCredit for CombinedCrew {
+    fn department(&self) -> &Department {
+        match self {
+            Self::Movie(inner) => inner.department(),
+            Self::Tv(inner) => inner.department(),
+            Self::Unknown(inner) => &inner.department,
+        }
+    }
+
+    fn has_job<F: Fn(&str) -> bool>(&self, predicate: F) -> bool {
+        predicate(self.job())
+    }
+}
+
+/// Filmography of a person, as returned by the `movie_credits`, `tv_credits`
+/// and `combined_credits` endpoints.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct PersonCredits<Cast, Crew> {
+    pub id: PersonId,
+    pub cast: Vec<Cast>,
+    pub crew: Vec<Crew>,
+}
+
+/// Sorts the most recent credits first. Credits without any date are usually
+/// announced projects, so they are kept at the top.
+fn sort_by_release_date<T: CreditedMedia>(items: &mut [T]) {
+    items.sort_by(
+        |left, right| match (left.release_date(), right.release_date()) {
+            (None, None) => std::cmp::Ordering::Equal,
+            (None, Some(_)) => std::cmp::Ordering::Less,
+            (Some(_), None) => std::cmp::Ordering::Greater,
+            (Some(left), Some(right)) => right.cmp(&left),
+        },
+    );
+}
+
+impl<Cast: CreditedMedia, Crew: CreditedMedia> PersonCredits<Cast, Crew> {
+    /// Sorts both cast and crew credits, most recent first.
+    pub fn sort_by_release_date(&mut self) {
+        sort_by_release_date(&mut self.cast);
+        sort_by_release_date(&mut self.crew);
+    }
+}
+
+impl<Cast, Crew: CrewCredit> PersonCredits<Cast, Crew> {
+    /// Groups the crew credits by department, keeping their order within each department.
+    pub fn crew_by_department(&self) -> BTreeMap<&Department, Vec<&Crew>> {
+        self.crew.iter().fold(BTreeMap::new(), |mut acc, item| {
+            acc.entry(item.department())
+                .or_insert_with(Vec::new)
+                .push(item);
+            acc
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        CombinedCast, CombinedCrew, CreditedMedia, KnownFor, MovieCast, MovieCrew, PersonCredits,
+    };
+    use crate::common::department::Department;
+
+    #[test]
+    fn should_keep_unknown_known_for() {
+        let value: KnownFor =
+            serde_json::from_str(r#"{"media_type":"person","id":287,"name":"Brad Pitt"}"#).unwrap();
+        assert!(
+            matches!(&value, KnownFor::Unknown(inner) if inner.media_type == "person" && inner.id == 287),
+            "{value:?}"
+        );
+    }
+
+    #[test]
+    fn should_fail_on_malformed_known_credit() {
+        let err = serde_json::from_str::<CombinedCast>(
+            r#"{"media_type":"movie","id":550,"name":"oops","credit_id":"abc"}"#,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("missing field"), "{err}");
+        assert!(serde_json::from_str::<CombinedCrew>(
+            r#"{"media_type":"tv","id":1399,"title":"oops","credit_id":"abc"}"#,
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn should_sort_by_release_date() {
+        let mut credits: PersonCredits<MovieCast, MovieCrew> =
+            serde_json::from_str(include_str!("../../assets/person-movie-credits.json")).unwrap();
+        credits.sort_by_release_date();
+        let dates = credits
+            .cast
+            .iter()
+            .map(|item| item.release_date().map(|date| date.to_string()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            dates,
+            vec![
+                None,
+                Some("1999-10-15".to_string()),
+                Some("1995-09-22".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn should_group_crew_by_department() {
+        let credits: PersonCredits<MovieCast, MovieCrew> =
+            serde_json::from_str(include_str!("../../assets/person-movie-credits.json")).unwrap();
+        let departments = credits.crew_by_department();
+        assert_eq!(
+            departments.keys().copied().collect::<Vec<_>>(),
+            vec![&Department::Crew, &Department::Production]
+        );
+        assert_eq!(departments[&Department::Production].len(), 2);
+        assert_eq!(departments[&Department::Crew][0].job, "Narrator");
+    }
+}
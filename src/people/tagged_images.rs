@@ -0,0 +1,237 @@
+use std::borrow::Cow;
+
+use crate::common::id::PersonId;
+use crate::common::image::Image;
+use crate::common::media::UnknownMedia;
+use crate::common::PaginatedResult;
+use crate::movie::MovieShort;
+use crate::tvshow::TVShowShort;
+use crate::util::tagged;
+
+/// Get the images in which a person has been tagged.
+///
+/// ```rust
+/// use tmdb_api::prelude::Command;
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::people::tagged_images::PersonTaggedImages;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into());
+///     let cmd = PersonTaggedImages::new(287);
+///     let result = cmd.execute(&client).await;
+///     match result {
+///         Ok(res) => println!("found: {:#?}", res),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct PersonTaggedImages {
+    /// ID of the person
+    pub person_id: PersonId,
+    /// Which page to query.
+    pub page: Option<u32>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "media_type", content = "media", rename_all = "lowercase")]
+pub enum TaggedMedia {
+    Movie(MovieShort),
+    Tv(TVShowShort),
+    /// Media type not known by this crate.
+    #[serde(untagged)]
+    Unknown(UnknownTaggedMedia),
+}
+
+impl<'de> serde::Deserialize<'de> for TaggedMedia {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (tag, mut value) = tagged::deserialize(deserializer, "media_type")?;
+        match tag.as_deref() {
+            Some("movie") => tagged::from_value(value["media"].take()).map(Self::Movie),
+            Some("tv") => tagged::from_value(value["media"].take()).map(Self::Tv),
+            _ => tagged::from_value(value).map(Self::Unknown),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct UnknownTaggedMedia {
+    pub media_type: String,
+    pub media: UnknownMedia,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct TaggedImage {
+    pub id: String,
+    #[serde(flatten)]
+    pub image: Image,
+    pub image_type: String,
+    #[serde(flatten)]
+    pub media: TaggedMedia,
+}
+
+impl PersonTaggedImages {
+    pub fn new(person_id: impl Into<PersonId>) -> Self {
+        Self {
+            person_id: person_id.into(),
+            page: None,
+        }
+    }
+
+    pub fn with_page(mut self, value: Option<u32>) -> Self {
+        self.page = value;
+        self
+    }
+}
+
+impl crate::prelude::Command for PersonTaggedImages {
+    type Output = PaginatedResult<TaggedImage>;
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Owned(format!("/person/{}/tagged_images", self.person_id))
+    }
+
+    fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
+        if let Some(page) = self.page {
+            vec![("page", Cow::Owned(page.to_string()))]
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::common::PaginatedResult;
+    use crate::prelude::Command;
+
+    use super::{PersonTaggedImages, TaggedImage, TaggedMedia};
+
+    #[tokio::test]
+    async fn it_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/person/287/tagged_images")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/person-tagged-images.json"))
+            .create_async()
+            .await;
+
+        let result = PersonTaggedImages::new(287).execute(&client).await.unwrap();
+        assert_eq!(result.page, 1);
+        let item = result.results.first().unwrap();
+        assert_eq!(item.image.file_path, "/hZkgoQYus5vegHoetLkCJzb17zJ.jpg");
+        assert!(matches!(&item.media, TaggedMedia::Movie(movie) if movie.inner.id == 550));
+        let item = result.results.last().unwrap();
+        assert!(matches!(&item.media, TaggedMedia::Tv(tvshow) if tvshow.inner.id == 1668));
+    }
+
+    #[test]
+    fn should_fail_on_malformed_known_media() {
+        let mut value: serde_json::Value =
+            serde_json::from_str(include_str!("../../assets/person-tagged-images.json")).unwrap();
+        let mut item = value["results"][0].take();
+        assert_eq!(item["media_type"], "movie");
+        item["media"] = serde_json::json!({"id": 550, "name": "oops"});
+        assert!(serde_json::from_value::<TaggedImage>(item).is_err());
+    }
+
+    #[test]
+    fn with_unknown_values() {
+        let mut value: serde_json::Value =
+            serde_json::from_str(include_str!("../../assets/person-tagged-images.json")).unwrap();
+        value["results"][0]["media_type"] = "podcast".into();
+        value["results"][0]["media"] = serde_json::json!({"id": 42, "name": "Some Podcast"});
+        let result: PaginatedResult<TaggedImage> = serde_json::from_value(value).unwrap();
+        let item = result.results.first().unwrap();
+        assert!(
+            matches!(&item.media, TaggedMedia::Unknown(inner) if inner.media_type == "podcast" && inner.media.title() == "Some Podcast")
+        );
+        let item = result.results.last().unwrap();
+        assert!(matches!(&item.media, TaggedMedia::Tv(tvshow) if tvshow.inner.id == 1668));
+    }
+
+    #[tokio::test]
+    async fn invalid_api_key() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/person/287/tagged_images")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(401)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/invalid-api-key.json"))
+            .create_async()
+            .await;
+
+        let err = PersonTaggedImages::new(287)
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 7);
+    }
+
+    #[tokio::test]
+    async fn resource_not_found() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/person/287/tagged_images")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/resource-not-found.json"))
+            .create_async()
+            .await;
+
+        let err = PersonTaggedImages::new(287)
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 34);
+    }
+}
+
+#[cfg(all(test, feature = "integration"))]
+mod integration_tests {
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::prelude::Command;
+
+    use super::PersonTaggedImages;
+
+    #[tokio::test]
+    async fn execute() {
+        let secret = std::env::var("TMDB_TOKEN_V3").unwrap();
+        let client = Client::<ReqwestExecutor>::new(secret);
+
+        let result = PersonTaggedImages::new(287).execute(&client).await.unwrap();
+        assert_eq!(result.page, 1);
+    }
+}
@@ -1,18 +1,44 @@
 use std::borrow::Cow;
 
-use crate::client::Executor;
+use crate::authentication::Session;
+use crate::client::{Executor, Method};
+
+pub use crate::common::credits::{CrewCredit, CrewList};
 
 #[async_trait::async_trait]
 pub trait Command {
     type Output: serde::de::DeserializeOwned;
 
+    fn method(&self) -> Method {
+        Method::Get
+    }
+
     fn path(&self) -> Cow<'static, str>;
     fn params(&self) -> Vec<(&'static str, Cow<'_, str>)>;
 
+    fn body(&self) -> Option<serde_json::Value> {
+        None
+    }
+
+    /// Whether the command acts on behalf of a user, in which case the session of the client
+    /// is attached when the command doesn't provide one.
+    fn needs_session(&self) -> bool {
+        false
+    }
+
     async fn execute<E: Executor + Send + Sync>(
         &self,
         client: &crate::Client<E>,
     ) -> Result<Self::Output, crate::error::Error> {
-        client.execute(self.path().as_ref(), self.params()).await
+        let mut params = self.params();
+        if self.needs_session() && !params.iter().any(|(key, _)| Session::is_param(key)) {
+            if let Some(session) = client.session() {
+                let (name, value) = session.as_param();
+                params.push((name, Cow::Borrowed(value)));
+            }
+        }
+        client
+            .send(self.method(), self.path().as_ref(), params, self.body())
+            .await
     }
 }
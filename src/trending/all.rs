@@ -0,0 +1,216 @@
+use crate::common::media::MediaShort;
+use crate::common::{PaginatedResult, TimeWindow};
+use std::borrow::Cow;
+
+/// Get a list of the current trending movies, tv shows and people on TMDB. This list updates daily.
+///
+/// ```rust
+/// use tmdb_api::prelude::Command;
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::trending::all::TrendingAll;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into());
+///     let result = TrendingAll::default().execute(&client).await;
+///     match result {
+///         Ok(res) => println!("found: {:#?}", res),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct TrendingAll {
+    /// ISO 639-1 value to display translated data for the fields that support it.
+    pub language: Option<String>,
+    /// Specify which page to query.
+    pub page: Option<u32>,
+    /// Time window
+    pub time_window: TimeWindow,
+}
+
+impl TrendingAll {
+    pub fn with_language(mut self, value: Option<String>) -> Self {
+        self.language = value;
+        self
+    }
+
+    pub fn with_page(mut self, value: Option<u32>) -> Self {
+        self.page = value;
+        self
+    }
+
+    pub fn with_time_window(mut self, value: TimeWindow) -> Self {
+        self.time_window = value;
+        self
+    }
+}
+
+impl crate::prelude::Command for TrendingAll {
+    type Output = PaginatedResult<MediaShort>;
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Owned(format!("/trending/all/{}", self.time_window))
+    }
+
+    fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
+        let mut res = Vec::new();
+        if let Some(ref language) = self.language {
+            res.push(("language", Cow::Borrowed(language.as_str())))
+        }
+        if let Some(ref page) = self.page {
+            res.push(("page", Cow::Owned(page.to_string())))
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TrendingAll;
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::common::media::MediaShort;
+    use crate::common::{PaginatedResult, TimeWindow};
+    use crate::prelude::Command;
+    use mockito::Matcher;
+
+    #[tokio::test]
+    async fn it_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/trending/all/day")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/trending-all.json"))
+            .create_async()
+            .await;
+
+        let result = TrendingAll::default().execute(&client).await.unwrap();
+        assert_eq!(result.page, 1);
+        assert!(
+            matches!(result.results[0], MediaShort::Movie(ref movie) if movie.inner.id == 1069387)
+        );
+        assert!(
+            matches!(result.results[1], MediaShort::Person(ref person) if person.inner.id == 287)
+        );
+        assert!(
+            matches!(result.results[2], MediaShort::Tv(ref tvshow) if tvshow.inner.id == 261579)
+        );
+    }
+
+    #[test]
+    fn with_unknown_values() {
+        let mut value: serde_json::Value =
+            serde_json::from_str(include_str!("../../assets/trending-all.json")).unwrap();
+        value["results"]
+            .as_array_mut()
+            .unwrap()
+            .push(serde_json::json!({
+                "id": 42,
+                "name": "Some Podcast",
+                "media_type": "podcast",
+            }));
+        let result: PaginatedResult<MediaShort> = serde_json::from_value(value).unwrap();
+        let item = result.results.last().unwrap();
+        assert!(matches!(item, MediaShort::Unknown(ref media) if media.media_type == "podcast"));
+        assert_eq!(item.id(), 42);
+        assert_eq!(item.title(), "Some Podcast");
+    }
+
+    #[tokio::test]
+    async fn should_use_time_window() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let m = server
+            .mock("GET", "/trending/all/week")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/trending-all.json"))
+            .create_async()
+            .await;
+
+        TrendingAll::default()
+            .with_time_window(TimeWindow::Week)
+            .execute(&client)
+            .await
+            .unwrap();
+        m.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn invalid_api_key() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/trending/all/day")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(401)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/invalid-api-key.json"))
+            .create_async()
+            .await;
+
+        let err = TrendingAll::default().execute(&client).await.unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 7);
+    }
+
+    #[tokio::test]
+    async fn resource_not_found() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/trending/all/day")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/resource-not-found.json"))
+            .create_async()
+            .await;
+
+        let err = TrendingAll::default().execute(&client).await.unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 34);
+    }
+}
+
+#[cfg(all(test, feature = "integration"))]
+mod integration_tests {
+    use super::TrendingAll;
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::prelude::Command;
+
+    #[tokio::test]
+    async fn execute() {
+        let secret = std::env::var("TMDB_TOKEN_V3").unwrap();
+        let client = Client::<ReqwestExecutor>::new(secret);
+
+        let _result = TrendingAll::default().execute(&client).await.unwrap();
+    }
+}
@@ -0,0 +1,192 @@
+use std::borrow::Cow;
+
+use crate::common::credits::AggregateCredits;
+use crate::common::id::TvId;
+
+/// Command to get the cast and crew of a tv show, aggregated over all the seasons
+///
+/// Unlike [`TvShowCredits`](crate::tvshow::credits::TvShowCredits), that only returns
+/// the cast of the latest season, each person comes with all the characters or jobs
+/// over the life of the show and the number of episodes for each of them.
+///
+/// ```rust
+/// use tmdb_api::prelude::Command;
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::tvshow::aggregate_credits::TVShowAggregateCredits;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into());
+///     let cmd = TVShowAggregateCredits::new(1399);
+///     let result = cmd.execute(&client).await;
+///     match result {
+///         Ok(res) => println!("found: {:#?}", res),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct TVShowAggregateCredits {
+    /// ID of the TV Show
+    pub tv_id: TvId,
+    /// ISO 639-1 value to display translated data for the fields that support it.
+    pub language: Option<String>,
+}
+
+impl TVShowAggregateCredits {
+    pub fn new(tv_id: impl Into<TvId>) -> Self {
+        Self {
+            tv_id: tv_id.into(),
+            language: None,
+        }
+    }
+
+    pub fn with_language(mut self, value: Option<String>) -> Self {
+        self.language = value;
+        self
+    }
+}
+
+impl crate::prelude::Command for TVShowAggregateCredits {
+    type Output = AggregateCredits;
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Owned(format!("/tv/{}/aggregate_credits", self.tv_id))
+    }
+
+    fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
+        if let Some(language) = self.language.as_ref() {
+            vec![("language", Cow::Borrowed(language.as_str()))]
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::common::department::Department;
+    use crate::prelude::Command;
+
+    use super::TVShowAggregateCredits;
+
+    #[tokio::test]
+    async fn it_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/tv/1399/aggregate_credits")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("api_key".into(), "secret".into()),
+                Matcher::UrlEncoded("language".into(), "en-US".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/tv-aggregate-credits.json"))
+            .create_async()
+            .await;
+
+        let result = TVShowAggregateCredits::new(1399)
+            .with_language(Some("en-US".into()))
+            .execute(&client)
+            .await
+            .unwrap();
+        assert_eq!(result.id, 1399);
+        let item = result.cast.first().unwrap();
+        assert_eq!(item.person.name, "Peter Dinklage");
+        assert_eq!(item.roles.first().unwrap().character, "Tyrion Lannister");
+        assert_eq!(item.total_episode_count, 67);
+        let item = result.cast.last().unwrap();
+        assert_eq!(item.roles.len(), 2);
+        assert_eq!(
+            item.roles.iter().map(|r| r.episode_count).sum::<u64>(),
+            item.total_episode_count
+        );
+        let item = result.crew.first().unwrap();
+        assert_eq!(item.department, Department::Production);
+        assert_eq!(item.jobs.first().unwrap().job, "Executive Producer");
+    }
+
+    #[tokio::test]
+    async fn invalid_api_key() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/tv/1399/aggregate_credits")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(401)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/invalid-api-key.json"))
+            .create_async()
+            .await;
+
+        let err = TVShowAggregateCredits::new(1399)
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 7);
+    }
+
+    #[tokio::test]
+    async fn resource_not_found() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/tv/1399/aggregate_credits")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/resource-not-found.json"))
+            .create_async()
+            .await;
+
+        let err = TVShowAggregateCredits::new(1399)
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 34);
+    }
+}
+
+#[cfg(all(test, feature = "integration"))]
+mod integration_tests {
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::prelude::Command;
+
+    use super::TVShowAggregateCredits;
+
+    #[tokio::test]
+    async fn execute() {
+        let secret = std::env::var("TMDB_TOKEN_V3").unwrap();
+        let client = Client::<ReqwestExecutor>::new(secret);
+
+        let result = TVShowAggregateCredits::new(1399)
+            .execute(&client)
+            .await
+            .unwrap();
+        assert_eq!(result.id, 1399);
+    }
+}
@@ -1,5 +1,7 @@
 use std::borrow::Cow;
 
+use crate::common::id::TvId;
+
 /// Get the content ratings that have been added to a tv.
 ///
 /// ```rust
@@ -22,18 +24,20 @@ use std::borrow::Cow;
 #[derive(Clone, Debug, Default)]
 pub struct TVShowContentRatings {
     /// ID of the show
-    pub series_id: u64,
+    pub series_id: TvId,
 }
 
 impl TVShowContentRatings {
-    pub fn new(series_id: u64) -> Self {
-        Self { series_id }
+    pub fn new(series_id: impl Into<TvId>) -> Self {
+        Self {
+            series_id: series_id.into(),
+        }
     }
 }
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct TVShowContentRatingsResult {
-    pub id: u64,
+    pub id: TvId,
     pub results: Vec<ContentRating>,
 }
 
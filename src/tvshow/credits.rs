@@ -1,6 +1,7 @@
 use std::borrow::Cow;
 
 use crate::common::credits::{Cast, Crew};
+use crate::common::id::TvId;
 
 /// Command to get alternative titles for a tv
 ///
@@ -24,15 +25,15 @@ use crate::common::credits::{Cast, Crew};
 #[derive(Clone, Debug, Default)]
 pub struct TvShowCredits {
     /// ID of the TvShow
-    pub series_id: u64,
+    pub series_id: TvId,
     /// ISO 639-1 value to display translated data for the fields that support it.
     pub language: Option<String>,
 }
 
 impl TvShowCredits {
-    pub fn new(series_id: u64) -> Self {
+    pub fn new(series_id: impl Into<TvId>) -> Self {
         Self {
-            series_id,
+            series_id: series_id.into(),
             language: None,
         }
     }
@@ -45,7 +46,7 @@ impl TvShowCredits {
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct TvShowCreditsResult {
-    pub id: u64,
+    pub id: TvId,
     pub cast: Vec<Cast>,
     pub crew: Vec<Crew>,
 }
@@ -1,5 +1,7 @@
 use std::borrow::Cow;
 
+use crate::common::id::TvId;
+
 /// Command to get the details of a tvshow
 ///
 /// ```rust
@@ -22,15 +24,15 @@ use std::borrow::Cow;
 #[derive(Clone, Debug, Default)]
 pub struct TVShowDetails {
     /// ID of the TV Show
-    pub tv_id: u64,
+    pub tv_id: TvId,
     /// ISO 639-1 value to display translated data for the fields that support it.
     pub language: Option<String>,
 }
 
 impl TVShowDetails {
-    pub fn new(tv_id: u64) -> Self {
+    pub fn new(tv_id: impl Into<TvId>) -> Self {
         Self {
-            tv_id,
+            tv_id: tv_id.into(),
             language: None,
         }
     }
@@ -63,6 +65,7 @@ mod tests {
     use crate::client::reqwest::ReqwestExecutor;
     use crate::client::Client;
     use crate::prelude::Command;
+    use crate::tvshow::{TVShowStatus, TVShowType};
     use mockito::Matcher;
 
     #[tokio::test]
@@ -85,6 +88,8 @@ mod tests {
 
         let result = TVShowDetails::new(1399).execute(&client).await.unwrap();
         assert_eq!(result.inner.id, 1399);
+        assert_eq!(result.status, TVShowStatus::Ended);
+        assert_eq!(result.ttype, TVShowType::Scripted);
     }
 
     #[tokio::test]
@@ -107,6 +112,7 @@ mod tests {
 
         let result = TVShowDetails::new(2).execute(&client).await.unwrap();
         assert_eq!(result.inner.id, 2);
+        assert_eq!(result.ttype, TVShowType::Miniseries);
     }
 
     #[tokio::test]
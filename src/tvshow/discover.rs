@@ -1,7 +1,131 @@
 use std::borrow::Cow;
+use std::fmt;
+
+use chrono::NaiveDate;
+
+use crate::common::filter::Filter;
+use crate::common::id::{CompanyId, KeywordId, NetworkId};
+use crate::tvshow::{TVShowStatus, TVShowType};
+use crate::watch_provider::WatchMonetizationTypes;
 
 const PATH: &str = "/discover/tv";
 
+/// Order of the tv shows returned by the discover command.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum SortBy {
+    FirstAirDateAsc,
+    FirstAirDateDesc,
+    NameAsc,
+    NameDesc,
+    OriginalNameAsc,
+    OriginalNameDesc,
+    PopularityAsc,
+    #[default]
+    PopularityDesc,
+    VoteAverageAsc,
+    VoteAverageDesc,
+    VoteCountAsc,
+    VoteCountDesc,
+    /// Raw value, sent as is.
+    Other(String),
+}
+
+impl fmt::Display for SortBy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::FirstAirDateAsc => write!(f, "first_air_date.asc"),
+            Self::FirstAirDateDesc => write!(f, "first_air_date.desc"),
+            Self::NameAsc => write!(f, "name.asc"),
+            Self::NameDesc => write!(f, "name.desc"),
+            Self::OriginalNameAsc => write!(f, "original_name.asc"),
+            Self::OriginalNameDesc => write!(f, "original_name.desc"),
+            Self::PopularityAsc => write!(f, "popularity.asc"),
+            Self::PopularityDesc => write!(f, "popularity.desc"),
+            Self::VoteAverageAsc => write!(f, "vote_average.asc"),
+            Self::VoteAverageDesc => write!(f, "vote_average.desc"),
+            Self::VoteCountAsc => write!(f, "vote_count.asc"),
+            Self::VoteCountDesc => write!(f, "vote_count.desc"),
+            Self::Other(value) => f.write_str(value),
+        }
+    }
+}
+
+/// Code of a tv show status in the `with_status` filter. Only the known statuses have one.
+///
+/// ```rust
+/// use tmdb_api::common::filter::Filter;
+/// use tmdb_api::tvshow::TVShowStatus;
+/// use tmdb_api::tvshow::discover::ShowStatusCode;
+///
+/// let filter = Filter::any([TVShowStatus::ReturningSeries, TVShowStatus::Pilot])
+///     .try_map(ShowStatusCode::try_from)
+///     .unwrap();
+/// assert_eq!(filter.to_string(), "0|5");
+/// assert!(ShowStatusCode::try_from(TVShowStatus::Unknown("On Hiatus".into())).is_err());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ShowStatusCode(u8);
+
+impl TryFrom<TVShowStatus> for ShowStatusCode {
+    /// The unknown statuses have no code.
+    type Error = TVShowStatus;
+
+    fn try_from(value: TVShowStatus) -> Result<Self, Self::Error> {
+        value.code().map(Self).ok_or(value)
+    }
+}
+
+impl fmt::Display for ShowStatusCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Code of a tv show type in the `with_type` filter. Only the known types have one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ShowTypeCode(u8);
+
+impl TryFrom<TVShowType> for ShowTypeCode {
+    /// The unknown types have no code.
+    type Error = TVShowType;
+
+    fn try_from(value: TVShowType) -> Result<Self, Self::Error> {
+        value.code().map(Self).ok_or(value)
+    }
+}
+
+impl fmt::Display for ShowTypeCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Command to discover tv shows matching a set of filters
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use tmdb_api::prelude::Command;
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::common::filter::Filter;
+/// use tmdb_api::common::id::NetworkId;
+/// use tmdb_api::tvshow::discover::TVShowDiscover;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into());
+///     // new shows on Netflix this month
+///     let cmd = TVShowDiscover::new()
+///         .with_networks(Some(Filter::all([NetworkId::from(213)])))
+///         .with_first_air_date_gte(NaiveDate::from_ymd_opt(2024, 3, 1))
+///         .with_first_air_date_lte(NaiveDate::from_ymd_opt(2024, 3, 31));
+///     let result = cmd.execute(&client).await;
+///     match result {
+///         Ok(res) => println!("found: {:#?}", res),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
 #[derive(Clone, Debug, Default)]
 pub struct TVShowDiscover {
     /// ISO 639-1 value to display translated data for the fields that support it.
@@ -10,6 +134,8 @@ pub struct TVShowDiscover {
     pub page: Option<u32>,
     /// Whether to include adult (pornography) content in the results.
     pub include_adult: bool,
+    /// Whether to include the tv shows without first air date.
+    pub include_null_first_air_dates: bool,
     /// ISO 3166-1 code to filter release region. Must be uppercase.
     pub region: Option<String>,
     /// ISO 3166-1 code to filter release region. Must be uppercase.
@@ -18,28 +144,51 @@ pub struct TVShowDiscover {
     pub with_origin_country: Option<String>,
     /// Language code. Must be lowercase.
     pub with_original_language: Option<String>,
-    /// Sort By
-    pub sort_by: Option<String>,
-    /// With watch monetization types
-    pub with_watch_monetization_types: Option<String>,
-    pub with_primary_release_date_gte: Option<String>,
+    pub sort_by: Option<SortBy>,
+    /// Monetization types, used with the watch region.
+    pub with_watch_monetization_types: Option<WatchMonetizationTypes>,
+    /// Only keep the tv shows with an episode aired after this date.
+    pub with_air_date_gte: Option<NaiveDate>,
+    /// Only keep the tv shows with an episode aired before this date.
+    pub with_air_date_lte: Option<NaiveDate>,
+    pub with_first_air_date_gte: Option<NaiveDate>,
+    pub with_first_air_date_lte: Option<NaiveDate>,
+    pub first_air_date_year: Option<u16>,
+    /// Timezone used by the air date filters, like `America/New_York`.
+    pub timezone: Option<String>,
+    /// Only keep the tv shows that were screened theatrically.
+    pub screened_theatrically: bool,
     pub with_vote_average_gte: Option<f32>,
+    pub with_vote_average_lte: Option<f32>,
+    pub with_vote_count_gte: Option<u64>,
+    pub with_vote_count_lte: Option<u64>,
+    /// Runtime of the episodes, in minutes.
+    pub with_runtime_gte: Option<u32>,
+    /// Runtime of the episodes, in minutes.
+    pub with_runtime_lte: Option<u32>,
+    /// IDs of the genres.
+    pub with_genres: Option<Filter<u64>>,
+    /// IDs of the genres.
+    pub without_genres: Option<Filter<u64>>,
+    /// IDs of the keywords.
+    pub with_keywords: Option<Filter<KeywordId>>,
+    /// IDs of the keywords.
+    pub without_keywords: Option<Filter<KeywordId>>,
+    /// IDs of the production companies.
+    pub with_companies: Option<Filter<CompanyId>>,
+    /// IDs of the production companies.
+    pub without_companies: Option<Filter<CompanyId>>,
+    /// IDs of the networks.
+    pub with_networks: Option<Filter<NetworkId>>,
+    pub with_status: Option<Filter<ShowStatusCode>>,
+    pub with_type: Option<Filter<ShowTypeCode>>,
 }
 
 impl TVShowDiscover {
     pub fn new() -> Self {
         Self {
-            language: None,
-            page: None,
-            include_adult: false,
-            region: None,
-            watch_region: None,
-            with_origin_country: None,
-            with_original_language: None,
-            sort_by: Some("popularity.desc".into()),
-            with_watch_monetization_types: None,
-            with_primary_release_date_gte: None,
-            with_vote_average_gte: None,
+            sort_by: Some(SortBy::default()),
+            ..Default::default()
         }
     }
 
@@ -58,6 +207,11 @@ impl TVShowDiscover {
         self
     }
 
+    pub fn with_include_null_first_air_dates(mut self, value: bool) -> Self {
+        self.include_null_first_air_dates = value;
+        self
+    }
+
     pub fn with_region(mut self, value: Option<String>) -> Self {
         self.region = value;
         self
@@ -78,18 +232,48 @@ impl TVShowDiscover {
         self
     }
 
-    pub fn with_sort_by(mut self, value: Option<String>) -> Self {
+    pub fn with_sort_by(mut self, value: Option<SortBy>) -> Self {
         self.sort_by = value;
         self
     }
 
-    pub fn with_watch_monetization_types(mut self, value: Option<String>) -> Self {
+    pub fn with_watch_monetization_types(mut self, value: Option<WatchMonetizationTypes>) -> Self {
         self.with_watch_monetization_types = value;
         self
     }
 
-    pub fn with_primary_release_date_gte(mut self, value: Option<String>) -> Self {
-        self.with_primary_release_date_gte = value;
+    pub fn with_air_date_gte(mut self, value: Option<NaiveDate>) -> Self {
+        self.with_air_date_gte = value;
+        self
+    }
+
+    pub fn with_air_date_lte(mut self, value: Option<NaiveDate>) -> Self {
+        self.with_air_date_lte = value;
+        self
+    }
+
+    pub fn with_first_air_date_gte(mut self, value: Option<NaiveDate>) -> Self {
+        self.with_first_air_date_gte = value;
+        self
+    }
+
+    pub fn with_first_air_date_lte(mut self, value: Option<NaiveDate>) -> Self {
+        self.with_first_air_date_lte = value;
+        self
+    }
+
+    pub fn with_first_air_date_year(mut self, value: Option<u16>) -> Self {
+        self.first_air_date_year = value;
+        self
+    }
+
+    pub fn with_timezone(mut self, value: Option<String>) -> Self {
+        self.timezone = value;
+        self
+    }
+
+    pub fn with_screened_theatrically(mut self, value: bool) -> Self {
+        self.screened_theatrically = value;
         self
     }
 
@@ -97,6 +281,76 @@ impl TVShowDiscover {
         self.with_vote_average_gte = value;
         self
     }
+
+    pub fn with_vote_average_lte(mut self, value: Option<f32>) -> Self {
+        self.with_vote_average_lte = value;
+        self
+    }
+
+    pub fn with_vote_count_gte(mut self, value: Option<u64>) -> Self {
+        self.with_vote_count_gte = value;
+        self
+    }
+
+    pub fn with_vote_count_lte(mut self, value: Option<u64>) -> Self {
+        self.with_vote_count_lte = value;
+        self
+    }
+
+    pub fn with_runtime_gte(mut self, value: Option<u32>) -> Self {
+        self.with_runtime_gte = value;
+        self
+    }
+
+    pub fn with_runtime_lte(mut self, value: Option<u32>) -> Self {
+        self.with_runtime_lte = value;
+        self
+    }
+
+    pub fn with_genres(mut self, value: Option<Filter<u64>>) -> Self {
+        self.with_genres = value;
+        self
+    }
+
+    pub fn without_genres(mut self, value: Option<Filter<u64>>) -> Self {
+        self.without_genres = value;
+        self
+    }
+
+    pub fn with_keywords(mut self, value: Option<Filter<KeywordId>>) -> Self {
+        self.with_keywords = value;
+        self
+    }
+
+    pub fn without_keywords(mut self, value: Option<Filter<KeywordId>>) -> Self {
+        self.without_keywords = value;
+        self
+    }
+
+    pub fn with_companies(mut self, value: Option<Filter<CompanyId>>) -> Self {
+        self.with_companies = value;
+        self
+    }
+
+    pub fn without_companies(mut self, value: Option<Filter<CompanyId>>) -> Self {
+        self.without_companies = value;
+        self
+    }
+
+    pub fn with_networks(mut self, value: Option<Filter<NetworkId>>) -> Self {
+        self.with_networks = value;
+        self
+    }
+
+    pub fn with_status(mut self, value: Option<Filter<ShowStatusCode>>) -> Self {
+        self.with_status = value;
+        self
+    }
+
+    pub fn with_type(mut self, value: Option<Filter<ShowTypeCode>>) -> Self {
+        self.with_type = value;
+        self
+    }
 }
 
 impl crate::prelude::Command for TVShowDiscover {
@@ -118,6 +372,9 @@ impl crate::prelude::Command for TVShowDiscover {
         if self.include_adult {
             res.push(("include_adult", Cow::Borrowed("true")));
         }
+        if self.include_null_first_air_dates {
+            res.push(("include_null_first_air_dates", Cow::Borrowed("true")));
+        }
         if let Some(region) = self.region.as_ref() {
             res.push(("region", Cow::Borrowed(region.as_str())));
         }
@@ -137,7 +394,7 @@ impl crate::prelude::Command for TVShowDiscover {
             ));
         }
         if let Some(sort_by) = self.sort_by.as_ref() {
-            res.push(("sort_by", Cow::Borrowed(sort_by.as_str())));
+            res.push(("sort_by", Cow::Owned(sort_by.to_string())));
         }
         if let Some(with_watch_monetization_types) = self.with_watch_monetization_types.as_ref() {
             res.push((
@@ -145,29 +402,87 @@ impl crate::prelude::Command for TVShowDiscover {
                 Cow::Owned(with_watch_monetization_types.to_string()),
             ));
         }
-        if let Some(with_primary_release_date_gte) = self.with_primary_release_date_gte.as_ref() {
-            res.push((
-                "primary_release_date.gte",
-                Cow::Owned(with_primary_release_date_gte.to_string()),
-            ));
+        if let Some(value) = self.with_air_date_gte {
+            res.push(("air_date.gte", Cow::Owned(value.to_string())));
         }
-        if let Some(with_vote_average_gte) = self.with_vote_average_gte {
-            res.push((
-                "vote_average.gte",
-                Cow::Owned(with_vote_average_gte.to_string()),
-            ));
+        if let Some(value) = self.with_air_date_lte {
+            res.push(("air_date.lte", Cow::Owned(value.to_string())));
+        }
+        if let Some(value) = self.with_first_air_date_gte {
+            res.push(("first_air_date.gte", Cow::Owned(value.to_string())));
+        }
+        if let Some(value) = self.with_first_air_date_lte {
+            res.push(("first_air_date.lte", Cow::Owned(value.to_string())));
+        }
+        if let Some(value) = self.first_air_date_year {
+            res.push(("first_air_date_year", Cow::Owned(value.to_string())));
+        }
+        if let Some(timezone) = self.timezone.as_ref() {
+            res.push(("timezone", Cow::Borrowed(timezone.as_str())));
+        }
+        if self.screened_theatrically {
+            res.push(("screened_theatrically", Cow::Borrowed("true")));
+        }
+        if let Some(value) = self.with_vote_average_gte {
+            res.push(("vote_average.gte", Cow::Owned(value.to_string())));
+        }
+        if let Some(value) = self.with_vote_average_lte {
+            res.push(("vote_average.lte", Cow::Owned(value.to_string())));
         }
-        
+        if let Some(value) = self.with_vote_count_gte {
+            res.push(("vote_count.gte", Cow::Owned(value.to_string())));
+        }
+        if let Some(value) = self.with_vote_count_lte {
+            res.push(("vote_count.lte", Cow::Owned(value.to_string())));
+        }
+        if let Some(value) = self.with_runtime_gte {
+            res.push(("with_runtime.gte", Cow::Owned(value.to_string())));
+        }
+        if let Some(value) = self.with_runtime_lte {
+            res.push(("with_runtime.lte", Cow::Owned(value.to_string())));
+        }
+        if let Some(value) = self.with_genres.as_ref() {
+            res.push(("with_genres", Cow::Owned(value.to_string())));
+        }
+        if let Some(value) = self.without_genres.as_ref() {
+            res.push(("without_genres", Cow::Owned(value.to_string())));
+        }
+        if let Some(value) = self.with_keywords.as_ref() {
+            res.push(("with_keywords", Cow::Owned(value.to_string())));
+        }
+        if let Some(value) = self.without_keywords.as_ref() {
+            res.push(("without_keywords", Cow::Owned(value.to_string())));
+        }
+        if let Some(value) = self.with_companies.as_ref() {
+            res.push(("with_companies", Cow::Owned(value.to_string())));
+        }
+        if let Some(value) = self.without_companies.as_ref() {
+            res.push(("without_companies", Cow::Owned(value.to_string())));
+        }
+        if let Some(value) = self.with_networks.as_ref() {
+            res.push(("with_networks", Cow::Owned(value.to_string())));
+        }
+        if let Some(value) = self.with_status.as_ref() {
+            res.push(("with_status", Cow::Owned(value.to_string())));
+        }
+        if let Some(value) = self.with_type.as_ref() {
+            res.push(("with_type", Cow::Owned(value.to_string())));
+        }
+
         res
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::TVShowDiscover;
+    use super::{ShowStatusCode, ShowTypeCode, SortBy, TVShowDiscover};
     use crate::client::reqwest::ReqwestExecutor;
     use crate::client::Client;
+    use crate::common::filter::Filter;
+    use crate::common::id::NetworkId;
     use crate::prelude::Command;
+    use crate::tvshow::{TVShowStatus, TVShowType};
+    use chrono::NaiveDate;
     use mockito::Matcher;
 
     #[tokio::test]
@@ -201,6 +516,69 @@ mod tests {
         assert_eq!(item.inner.name, "Binnelanders");
     }
 
+    #[tokio::test]
+    async fn should_send_tv_filters() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let cmd = TVShowDiscover::new()
+            .with_networks(Some(Filter::all([NetworkId::from(213)])))
+            .with_first_air_date_gte(NaiveDate::from_ymd_opt(2024, 3, 1))
+            .with_first_air_date_lte(NaiveDate::from_ymd_opt(2024, 3, 31))
+            .with_timezone(Some("Europe/Paris".into()))
+            .with_genres(Some(Filter::any([18, 80])))
+            .without_genres(Some(Filter::all([16, 10762])))
+            .with_status(Some(
+                Filter::any([TVShowStatus::ReturningSeries, TVShowStatus::Pilot])
+                    .try_map(ShowStatusCode::try_from)
+                    .unwrap(),
+            ))
+            .with_type(Some(
+                Filter::all([TVShowType::Scripted])
+                    .try_map(ShowTypeCode::try_from)
+                    .unwrap(),
+            ))
+            .with_runtime_lte(Some(60))
+            .with_screened_theatrically(true)
+            .with_sort_by(Some(SortBy::FirstAirDateAsc));
+
+        let m = server
+            .mock("GET", super::PATH)
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("api_key".into(), "secret".into()),
+                Matcher::UrlEncoded("with_networks".into(), "213".into()),
+                Matcher::UrlEncoded("first_air_date.gte".into(), "2024-03-01".into()),
+                Matcher::UrlEncoded("first_air_date.lte".into(), "2024-03-31".into()),
+                Matcher::UrlEncoded("timezone".into(), "Europe/Paris".into()),
+                Matcher::UrlEncoded("with_genres".into(), "18|80".into()),
+                Matcher::UrlEncoded("without_genres".into(), "16,10762".into()),
+                Matcher::UrlEncoded("with_status".into(), "0|5".into()),
+                Matcher::UrlEncoded("with_type".into(), "4".into()),
+                Matcher::UrlEncoded("with_runtime.lte".into(), "60".into()),
+                Matcher::UrlEncoded("screened_theatrically".into(), "true".into()),
+                Matcher::UrlEncoded("sort_by".into(), "first_air_date.asc".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/discover-tv.json"))
+            .create_async()
+            .await;
+        cmd.execute(&client).await.unwrap();
+        m.assert_async().await;
+    }
+
+    #[test]
+    fn should_reject_unknown_status_and_type() {
+        let status = TVShowStatus::Unknown("On Hiatus".into());
+        assert_eq!(ShowStatusCode::try_from(status.clone()), Err(status));
+        let kind = TVShowType::Unknown("Animation".into());
+        assert_eq!(ShowTypeCode::try_from(kind.clone()), Err(kind));
+    }
+
     #[tokio::test]
     async fn invalid_api_key() {
         let mut server = mockito::Server::new_async().await;
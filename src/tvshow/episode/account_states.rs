@@ -0,0 +1,147 @@
+use std::borrow::Cow;
+
+use crate::account::AccountStates;
+use crate::authentication::Session;
+use crate::common::id::TvId;
+
+/// Get the rating, watchlist and favorite status of a tv show episode for the account owning the session.
+///
+/// ```rust
+/// use tmdb_api::prelude::Command;
+/// use tmdb_api::authentication::Session;
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::tvshow::episode::account_states::TVShowEpisodeAccountStates;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into())
+///         .with_session(Some(Session::User("session-id".into())));
+///     let result = TVShowEpisodeAccountStates::new(1399, 1, 1).execute(&client).await;
+///     match result {
+///         Ok(res) => println!("found: {:#?}", res),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct TVShowEpisodeAccountStates {
+    /// ID of the TV show.
+    pub tv_id: TvId,
+    /// Number of the season.
+    pub season_number: u64,
+    /// Number of the episode.
+    pub episode_number: u64,
+    /// Session to use instead of the one of the client.
+    pub session: Option<Session>,
+}
+
+impl TVShowEpisodeAccountStates {
+    pub fn new(tv_id: impl Into<TvId>, season_number: u64, episode_number: u64) -> Self {
+        Self {
+            tv_id: tv_id.into(),
+            season_number,
+            episode_number,
+            session: None,
+        }
+    }
+
+    pub fn with_session(mut self, value: Option<Session>) -> Self {
+        self.session = value;
+        self
+    }
+}
+
+impl crate::prelude::Command for TVShowEpisodeAccountStates {
+    type Output = AccountStates;
+
+    fn needs_session(&self) -> bool {
+        true
+    }
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Owned(format!(
+            "/tv/{}/season/{}/episode/{}/account_states",
+            self.tv_id, self.season_number, self.episode_number
+        ))
+    }
+
+    fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
+        let mut res = Vec::new();
+        if let Some(ref session) = self.session {
+            let (name, value) = session.as_param();
+            res.push((name, Cow::Borrowed(value)));
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+
+    use crate::authentication::Session;
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::prelude::Command;
+
+    use super::TVShowEpisodeAccountStates;
+
+    #[tokio::test]
+    async fn it_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .with_session(Some(Session::User("session".into())))
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/tv/1399/season/1/episode/1/account_states")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("api_key".into(), "secret".into()),
+                Matcher::UrlEncoded("session_id".into(), "session".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!(
+                "../../../assets/tv-episode-account-states.json"
+            ))
+            .create_async()
+            .await;
+
+        let result = TVShowEpisodeAccountStates::new(1399, 1, 1)
+            .execute(&client)
+            .await
+            .unwrap();
+        assert_eq!(result.id, 63056);
+        assert_eq!(result.rated, Some(10.0));
+    }
+
+    #[tokio::test]
+    async fn invalid_api_key() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/tv/1399/season/1/episode/1/account_states")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(401)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../../assets/invalid-api-key.json"))
+            .create_async()
+            .await;
+
+        let err = TVShowEpisodeAccountStates::new(1399, 1, 1)
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 7);
+    }
+}
@@ -0,0 +1,194 @@
+use std::borrow::Cow;
+
+use crate::common::credits::Cast;
+use crate::common::id::{EpisodeId, TvId};
+use crate::tvshow::{EpisodeCrew, GuestStar};
+
+/// Command to get the cast, crew and guest stars of a tv show episode
+///
+/// ```rust
+/// use tmdb_api::prelude::Command;
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::tvshow::episode::credits::TVShowEpisodeCredits;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into());
+///     let cmd = TVShowEpisodeCredits::new(1399, 1, 1);
+///     let result = cmd.execute(&client).await;
+///     match result {
+///         Ok(res) => println!("found: {:#?}", res),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct TVShowEpisodeCredits {
+    /// ID of the TV Show
+    pub tv_id: TvId,
+    /// Number of the season
+    pub season_number: u64,
+    /// Number of the episode
+    pub episode_number: u64,
+    /// ISO 639-1 value to display translated data for the fields that support it.
+    pub language: Option<String>,
+}
+
+impl TVShowEpisodeCredits {
+    pub fn new(tv_id: impl Into<TvId>, season_number: u64, episode_number: u64) -> Self {
+        Self {
+            tv_id: tv_id.into(),
+            season_number,
+            episode_number,
+            language: None,
+        }
+    }
+
+    pub fn with_language(mut self, value: Option<String>) -> Self {
+        self.language = value;
+        self
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct TVShowEpisodeCreditsResult {
+    pub id: EpisodeId,
+    pub cast: Vec<Cast>,
+    pub crew: Vec<EpisodeCrew>,
+    pub guest_stars: Vec<GuestStar>,
+}
+
+impl crate::prelude::Command for TVShowEpisodeCredits {
+    type Output = TVShowEpisodeCreditsResult;
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Owned(format!(
+            "/tv/{}/season/{}/episode/{}/credits",
+            self.tv_id, self.season_number, self.episode_number
+        ))
+    }
+
+    fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
+        if let Some(language) = self.language.as_ref() {
+            vec![("language", Cow::Borrowed(language.as_str()))]
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::prelude::{Command, CrewCredit};
+
+    use super::TVShowEpisodeCredits;
+
+    #[tokio::test]
+    async fn it_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/tv/1399/season/1/episode/1/credits")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../../assets/tv-episode-credits.json"))
+            .create_async()
+            .await;
+
+        let result = TVShowEpisodeCredits::new(1399, 1, 1)
+            .execute(&client)
+            .await
+            .unwrap();
+        assert_eq!(result.id, 63056);
+        let item = result.cast.first().unwrap();
+        assert_eq!(item.character, "Tyrion Lannister");
+        let item = result.crew.first().unwrap();
+        assert!(item.is_director());
+        let item = result.guest_stars.first().unwrap();
+        assert_eq!(item.inner.character, "Benjen Stark");
+    }
+
+    #[tokio::test]
+    async fn invalid_api_key() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/tv/1399/season/1/episode/1/credits")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(401)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../../assets/invalid-api-key.json"))
+            .create_async()
+            .await;
+
+        let err = TVShowEpisodeCredits::new(1399, 1, 1)
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 7);
+    }
+
+    #[tokio::test]
+    async fn resource_not_found() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/tv/1399/season/1/episode/1/credits")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../../assets/resource-not-found.json"))
+            .create_async()
+            .await;
+
+        let err = TVShowEpisodeCredits::new(1399, 1, 1)
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 34);
+    }
+}
+
+#[cfg(all(test, feature = "integration"))]
+mod integration_tests {
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::prelude::Command;
+
+    use super::TVShowEpisodeCredits;
+
+    #[tokio::test]
+    async fn execute() {
+        let secret = std::env::var("TMDB_TOKEN_V3").unwrap();
+        let client = Client::<ReqwestExecutor>::new(secret);
+
+        let result = TVShowEpisodeCredits::new(1399, 1, 1)
+            .execute(&client)
+            .await
+            .unwrap();
+        assert_eq!(result.id, 63056);
+    }
+}
@@ -1,5 +1,7 @@
 use std::borrow::Cow;
 
+use crate::common::id::TvId;
+
 /// Command to get the details of a tvshow episode
 ///
 /// ```rust
@@ -22,7 +24,7 @@ use std::borrow::Cow;
 #[derive(Clone, Debug, Default)]
 pub struct TVShowEpisodeDetails {
     /// ID of the TV Show
-    pub tv_id: u64,
+    pub tv_id: TvId,
     /// Number of the season
     pub season_number: u64,
     /// Number of the episode
@@ -32,9 +34,9 @@ pub struct TVShowEpisodeDetails {
 }
 
 impl TVShowEpisodeDetails {
-    pub fn new(tv_id: u64, season_number: u64, episode_number: u64) -> Self {
+    pub fn new(tv_id: impl Into<TvId>, season_number: u64, episode_number: u64) -> Self {
         Self {
-            tv_id,
+            tv_id: tv_id.into(),
             season_number,
             episode_number,
             language: None,
@@ -97,6 +99,21 @@ mod tests {
             .await
             .unwrap();
         assert_eq!(result.inner.id, 63056);
+        let directors: Vec<_> = result
+            .directors()
+            .into_iter()
+            .map(|item| item.inner.person.name.as_str())
+            .collect();
+        assert_eq!(directors, vec!["Timothy Van Patten"]);
+        let writers: Vec<_> = result
+            .writers()
+            .into_iter()
+            .map(|item| item.inner.person.name.as_str())
+            .collect();
+        assert_eq!(writers, vec!["David Benioff", "D.B. Weiss"]);
+        let guest = result.guest_stars.first().unwrap();
+        assert_eq!(guest.inner.character, "Benjen Stark");
+        assert_eq!(guest.inner.credit.credit_id, "5256c8b919c2956ff604836a");
     }
 
     #[tokio::test]
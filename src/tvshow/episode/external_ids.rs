@@ -0,0 +1,184 @@
+use std::borrow::Cow;
+
+use crate::common::id::{EpisodeId, TvId};
+
+/// Get the external ids of a tv show episode, like its IMDb or TVDB ids.
+///
+/// ```rust
+/// use tmdb_api::prelude::Command;
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::tvshow::episode::external_ids::TVShowEpisodeExternalIds;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into());
+///     let cmd = TVShowEpisodeExternalIds::new(1399, 1, 1);
+///     let result = cmd.execute(&client).await;
+///     match result {
+///         Ok(res) => println!("found: {:#?}", res),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct TVShowEpisodeExternalIds {
+    /// ID of the TV Show
+    pub tv_id: TvId,
+    /// Number of the season
+    pub season_number: u64,
+    /// Number of the episode
+    pub episode_number: u64,
+}
+
+impl TVShowEpisodeExternalIds {
+    pub fn new(tv_id: impl Into<TvId>, season_number: u64, episode_number: u64) -> Self {
+        Self {
+            tv_id: tv_id.into(),
+            season_number,
+            episode_number,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct TVShowEpisodeExternalIdsResult {
+    pub id: EpisodeId,
+    #[serde(default, deserialize_with = "crate::util::empty_string::deserialize")]
+    pub imdb_id: Option<String>,
+    #[serde(default, deserialize_with = "crate::util::empty_string::deserialize")]
+    pub freebase_mid: Option<String>,
+    #[serde(default, deserialize_with = "crate::util::empty_string::deserialize")]
+    pub freebase_id: Option<String>,
+    pub tvdb_id: Option<u64>,
+    pub tvrage_id: Option<u64>,
+    #[serde(default, deserialize_with = "crate::util::empty_string::deserialize")]
+    pub wikidata_id: Option<String>,
+}
+
+impl crate::prelude::Command for TVShowEpisodeExternalIds {
+    type Output = TVShowEpisodeExternalIdsResult;
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Owned(format!(
+            "/tv/{}/season/{}/episode/{}/external_ids",
+            self.tv_id, self.season_number, self.episode_number
+        ))
+    }
+
+    fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
+        Vec::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::prelude::Command;
+
+    use super::TVShowEpisodeExternalIds;
+
+    #[tokio::test]
+    async fn it_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/tv/1399/season/1/episode/1/external_ids")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../../assets/tv-episode-external-ids.json"))
+            .create_async()
+            .await;
+
+        let result = TVShowEpisodeExternalIds::new(1399, 1, 1)
+            .execute(&client)
+            .await
+            .unwrap();
+        assert_eq!(result.id, 63056);
+        assert_eq!(result.imdb_id.as_deref(), Some("tt1480055"));
+        assert_eq!(result.tvdb_id, Some(3254641));
+        assert!(result.freebase_id.is_none());
+    }
+
+    #[tokio::test]
+    async fn invalid_api_key() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/tv/1399/season/1/episode/1/external_ids")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(401)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../../assets/invalid-api-key.json"))
+            .create_async()
+            .await;
+
+        let err = TVShowEpisodeExternalIds::new(1399, 1, 1)
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 7);
+    }
+
+    #[tokio::test]
+    async fn resource_not_found() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/tv/1399/season/1/episode/1/external_ids")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../../assets/resource-not-found.json"))
+            .create_async()
+            .await;
+
+        let err = TVShowEpisodeExternalIds::new(1399, 1, 1)
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 34);
+    }
+}
+
+#[cfg(all(test, feature = "integration"))]
+mod integration_tests {
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::prelude::Command;
+
+    use super::TVShowEpisodeExternalIds;
+
+    #[tokio::test]
+    async fn execute() {
+        let secret = std::env::var("TMDB_TOKEN_V3").unwrap();
+        let client = Client::<ReqwestExecutor>::new(secret);
+
+        let result = TVShowEpisodeExternalIds::new(1399, 1, 1)
+            .execute(&client)
+            .await
+            .unwrap();
+        assert_eq!(result.id, 63056);
+    }
+}
@@ -1,2 +1,18 @@
 #[cfg(feature = "commands")]
+pub mod account_states;
+#[cfg(feature = "commands")]
+pub mod add_rating;
+#[cfg(feature = "commands")]
+pub mod credits;
+#[cfg(feature = "commands")]
+pub mod delete_rating;
+#[cfg(feature = "commands")]
 pub mod details;
+#[cfg(feature = "commands")]
+pub mod external_ids;
+#[cfg(feature = "commands")]
+pub mod images;
+#[cfg(feature = "commands")]
+pub mod translations;
+#[cfg(feature = "commands")]
+pub mod videos;
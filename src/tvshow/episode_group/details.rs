@@ -0,0 +1,177 @@
+use std::borrow::Cow;
+
+use super::EpisodeGroup;
+
+/// Command to get the details of an episode group, with its groups and their episodes
+///
+/// ```rust
+/// use tmdb_api::prelude::Command;
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::tvshow::episode_group::details::TVShowEpisodeGroupDetails;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into());
+///     let cmd = TVShowEpisodeGroupDetails::new("5c1fbd8a0e0a26191b6e7a00".into());
+///     let result = cmd.execute(&client).await;
+///     match result {
+///         Ok(res) => println!("found: {:#?}", res),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct TVShowEpisodeGroupDetails {
+    /// ID of the episode group
+    pub episode_group_id: String,
+    /// ISO 639-1 value to display translated data for the fields that support it.
+    pub language: Option<String>,
+}
+
+impl TVShowEpisodeGroupDetails {
+    pub fn new(episode_group_id: String) -> Self {
+        Self {
+            episode_group_id,
+            language: None,
+        }
+    }
+
+    pub fn with_language(mut self, value: Option<String>) -> Self {
+        self.language = value;
+        self
+    }
+}
+
+impl crate::prelude::Command for TVShowEpisodeGroupDetails {
+    type Output = EpisodeGroup;
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Owned(format!("/tv/episode_group/{}", self.episode_group_id))
+    }
+
+    fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
+        if let Some(language) = self.language.as_ref() {
+            vec![("language", Cow::Borrowed(language.as_str()))]
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::prelude::Command;
+
+    use super::TVShowEpisodeGroupDetails;
+    use crate::tvshow::episode_group::EpisodeGroupKind;
+
+    #[tokio::test]
+    async fn it_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/tv/episode_group/5c1fbd8a0e0a26191b6e7a00")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!(
+                "../../../assets/tv-episode-group-details.json"
+            ))
+            .create_async()
+            .await;
+
+        let result = TVShowEpisodeGroupDetails::new("5c1fbd8a0e0a26191b6e7a00".into())
+            .execute(&client)
+            .await
+            .unwrap();
+        assert_eq!(result.inner.id, "5c1fbd8a0e0a26191b6e7a00");
+        assert_eq!(result.inner.kind, EpisodeGroupKind::Absolute);
+        assert_eq!(result.groups.len(), 2);
+        let episode = result.groups.first().unwrap().episodes.first().unwrap();
+        assert_eq!(episode.inner.season_number, 2);
+        assert_eq!(episode.order, 1);
+    }
+
+    #[tokio::test]
+    async fn invalid_api_key() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/tv/episode_group/5c1fbd8a0e0a26191b6e7a00")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(401)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../../assets/invalid-api-key.json"))
+            .create_async()
+            .await;
+
+        let err = TVShowEpisodeGroupDetails::new("5c1fbd8a0e0a26191b6e7a00".into())
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 7);
+    }
+
+    #[tokio::test]
+    async fn resource_not_found() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/tv/episode_group/5c1fbd8a0e0a26191b6e7a00")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../../assets/resource-not-found.json"))
+            .create_async()
+            .await;
+
+        let err = TVShowEpisodeGroupDetails::new("5c1fbd8a0e0a26191b6e7a00".into())
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 34);
+    }
+}
+
+#[cfg(all(test, feature = "integration"))]
+mod integration_tests {
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::prelude::Command;
+
+    use super::TVShowEpisodeGroupDetails;
+
+    #[tokio::test]
+    async fn execute() {
+        let secret = std::env::var("TMDB_TOKEN_V3").unwrap();
+        let client = Client::<ReqwestExecutor>::new(secret);
+
+        let result = TVShowEpisodeGroupDetails::new("5c1fbd8a0e0a26191b6e7a00".into())
+            .execute(&client)
+            .await
+            .unwrap();
+        assert!(!result.groups.is_empty());
+    }
+}
@@ -0,0 +1,283 @@
+//! Episode groups are alternative orderings of the episodes of a tv show,
+//! like the DVD order or the absolute numbering often used for anime.
+
+#[cfg(feature = "commands")]
+pub mod details;
+
+use std::collections::HashMap;
+
+use crate::tvshow::{EpisodeShort, NetworkShort};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum EpisodeGroupKind {
+    OriginalAirDate,
+    Absolute,
+    Dvd,
+    Digital,
+    StoryArc,
+    Production,
+    Tv,
+    /// Kind not known by this crate, with its raw code.
+    Unknown(u8),
+}
+
+impl From<u8> for EpisodeGroupKind {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => Self::OriginalAirDate,
+            2 => Self::Absolute,
+            3 => Self::Dvd,
+            4 => Self::Digital,
+            5 => Self::StoryArc,
+            6 => Self::Production,
+            7 => Self::Tv,
+            other => Self::Unknown(other),
+        }
+    }
+}
+
+impl From<EpisodeGroupKind> for u8 {
+    fn from(value: EpisodeGroupKind) -> Self {
+        match value {
+            EpisodeGroupKind::OriginalAirDate => 1,
+            EpisodeGroupKind::Absolute => 2,
+            EpisodeGroupKind::Dvd => 3,
+            EpisodeGroupKind::Digital => 4,
+            EpisodeGroupKind::StoryArc => 5,
+            EpisodeGroupKind::Production => 6,
+            EpisodeGroupKind::Tv => 7,
+            EpisodeGroupKind::Unknown(other) => other,
+        }
+    }
+}
+
+impl serde::Serialize for EpisodeGroupKind {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8(u8::from(*self))
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for EpisodeGroupKind {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        u8::deserialize(deserializer).map(Self::from)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct EpisodeGroupShort {
+    pub id: String,
+    pub name: String,
+    #[serde(deserialize_with = "crate::util::empty_string::deserialize")]
+    pub description: Option<String>,
+    pub episode_count: u64,
+    pub group_count: u64,
+    #[serde(rename = "type")]
+    pub kind: EpisodeGroupKind,
+    pub network: Option<NetworkShort>,
+}
+
+/// Episode as ordered in a group.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GroupEpisode {
+    #[serde(flatten)]
+    pub inner: EpisodeShort,
+    /// Position of the episode in the group, starting at 0.
+    pub order: u64,
+}
+
+/// Group of episodes, that plays the role of a season in the ordering.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct Group {
+    pub id: String,
+    pub name: String,
+    /// Position of the group in the episode group, starting at 0.
+    pub order: u64,
+    pub locked: bool,
+    pub episodes: Vec<GroupEpisode>,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct EpisodeGroup {
+    #[serde(flatten)]
+    pub inner: EpisodeGroupShort,
+    pub groups: Vec<Group>,
+}
+
+impl EpisodeGroup {
+    pub fn mapping(&self) -> EpisodeGroupMapping {
+        EpisodeGroupMapping::new(self)
+    }
+}
+
+/// Position of an episode in the aired ordering of a tv show.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AiredPosition {
+    pub season_number: u64,
+    pub episode_number: u64,
+}
+
+/// Position of an episode in an episode group.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct GroupPosition {
+    /// Order of the group, starting at 0.
+    pub group_order: u64,
+    /// Order of the episode in the group, starting at 0.
+    pub episode_order: u64,
+}
+
+/// Converts the position of episodes between the aired ordering and the ordering of an episode group.
+///
+/// The absolute number of an episode is its position, starting at 1, when walking through
+/// the groups and their episodes in order. An episode listed in several groups is counted once,
+/// at its first position, so that the absolute numbers convert back to the same episode.
+///
+/// ```rust
+/// use tmdb_api::tvshow::episode_group::{AiredPosition, EpisodeGroup};
+///
+/// fn print_absolute(group: &EpisodeGroup) {
+///     let mapping = group.mapping();
+///     let aired = AiredPosition { season_number: 2, episode_number: 1 };
+///     if let Some(absolute) = mapping.to_absolute(aired) {
+///         println!("S02E01 is episode {absolute}");
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct EpisodeGroupMapping {
+    /// First position of each episode, in the absolute order.
+    entries: Vec<(AiredPosition, GroupPosition)>,
+    by_aired: HashMap<AiredPosition, usize>,
+    by_group: HashMap<GroupPosition, AiredPosition>,
+}
+
+impl EpisodeGroupMapping {
+    pub fn new(group: &EpisodeGroup) -> Self {
+        let mut groups: Vec<&Group> = group.groups.iter().collect();
+        groups.sort_by_key(|item| item.order);
+
+        let mut res = Self::default();
+        for item in groups {
+            let mut episodes: Vec<&GroupEpisode> = item.episodes.iter().collect();
+            episodes.sort_by_key(|episode| episode.order);
+            for episode in episodes {
+                let aired = AiredPosition {
+                    season_number: episode.inner.season_number,
+                    episode_number: episode.inner.episode_number,
+                };
+                let position = GroupPosition {
+                    group_order: item.order,
+                    episode_order: episode.order,
+                };
+                res.by_group.insert(position, aired);
+                // an episode can appear in several groups, the first one wins
+                if !res.by_aired.contains_key(&aired) {
+                    res.by_aired.insert(aired, res.entries.len());
+                    res.entries.push((aired, position));
+                }
+            }
+        }
+        res
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn to_group(&self, aired: AiredPosition) -> Option<GroupPosition> {
+        self.by_aired
+            .get(&aired)
+            .map(|index| self.entries[*index].1)
+    }
+
+    pub fn to_aired(&self, position: GroupPosition) -> Option<AiredPosition> {
+        self.by_group.get(&position).copied()
+    }
+
+    pub fn to_absolute(&self, aired: AiredPosition) -> Option<u64> {
+        self.by_aired.get(&aired).map(|index| *index as u64 + 1)
+    }
+
+    pub fn from_absolute(&self, absolute: u64) -> Option<AiredPosition> {
+        let index = usize::try_from(absolute.checked_sub(1)?).ok()?;
+        self.entries.get(index).map(|(aired, _)| *aired)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AiredPosition, EpisodeGroup, EpisodeGroupKind, GroupPosition};
+
+    fn aired(season_number: u64, episode_number: u64) -> AiredPosition {
+        AiredPosition {
+            season_number,
+            episode_number,
+        }
+    }
+
+    #[test]
+    fn should_map_positions() {
+        let group: EpisodeGroup = serde_json::from_str(include_str!(
+            "../../../assets/tv-episode-group-details.json"
+        ))
+        .unwrap();
+        assert_eq!(group.inner.kind, EpisodeGroupKind::Absolute);
+
+        let mapping = group.mapping();
+        assert_eq!(mapping.len(), 5);
+        assert_eq!(mapping.to_absolute(aired(1, 1)), Some(1));
+        assert_eq!(mapping.to_absolute(aired(2, 1)), Some(4));
+        assert_eq!(mapping.to_absolute(aired(3, 1)), None);
+        assert_eq!(mapping.from_absolute(5), Some(aired(2, 2)));
+        assert_eq!(mapping.from_absolute(0), None);
+        assert_eq!(mapping.from_absolute(6), None);
+        assert_eq!(
+            mapping.to_group(aired(2, 1)),
+            Some(GroupPosition {
+                group_order: 1,
+                episode_order: 0,
+            })
+        );
+        assert_eq!(
+            mapping.to_aired(GroupPosition {
+                group_order: 0,
+                episode_order: 2,
+            }),
+            Some(aired(1, 3))
+        );
+    }
+
+    #[test]
+    fn should_round_trip_duplicated_episodes() {
+        let mut value: serde_json::Value = serde_json::from_str(include_str!(
+            "../../../assets/tv-episode-group-details.json"
+        ))
+        .unwrap();
+        // lists S01E01 again at the end of the second group
+        let groups = value["groups"].as_array_mut().unwrap();
+        let first = groups.iter().position(|group| group["order"] == 0).unwrap();
+        let mut duplicate = groups[first]["episodes"][0].clone();
+        duplicate["order"] = 2.into();
+        let second = groups.iter_mut().find(|group| group["order"] == 1).unwrap();
+        second["episodes"].as_array_mut().unwrap().push(duplicate);
+        let group: EpisodeGroup = serde_json::from_value(value).unwrap();
+
+        let mapping = group.mapping();
+        assert_eq!(mapping.len(), 5);
+        for absolute in 1..=5 {
+            let aired = mapping.from_absolute(absolute).unwrap();
+            assert_eq!(mapping.to_absolute(aired), Some(absolute));
+        }
+        assert_eq!(mapping.to_absolute(aired(1, 1)), Some(1));
+        assert_eq!(
+            mapping.to_aired(GroupPosition {
+                group_order: 1,
+                episode_order: 2,
+            }),
+            Some(aired(1, 1))
+        );
+    }
+}
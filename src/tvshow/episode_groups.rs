@@ -0,0 +1,183 @@
+use std::borrow::Cow;
+
+use crate::common::id::TvId;
+use crate::tvshow::episode_group::EpisodeGroupShort;
+
+/// Command to get the episode groups of a tv show
+///
+/// ```rust
+/// use tmdb_api::prelude::Command;
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::tvshow::episode_groups::TVShowEpisodeGroups;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into());
+///     let cmd = TVShowEpisodeGroups::new(63926);
+///     let result = cmd.execute(&client).await;
+///     match result {
+///         Ok(res) => println!("found: {:#?}", res),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct TVShowEpisodeGroups {
+    /// ID of the TV Show
+    pub tv_id: TvId,
+}
+
+impl TVShowEpisodeGroups {
+    pub fn new(tv_id: impl Into<TvId>) -> Self {
+        Self {
+            tv_id: tv_id.into(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct TVShowEpisodeGroupsResult {
+    pub id: TvId,
+    pub results: Vec<EpisodeGroupShort>,
+}
+
+impl crate::prelude::Command for TVShowEpisodeGroups {
+    type Output = TVShowEpisodeGroupsResult;
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Owned(format!("/tv/{}/episode_groups", self.tv_id))
+    }
+
+    fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
+        Vec::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::prelude::Command;
+
+    use super::{TVShowEpisodeGroups, TVShowEpisodeGroupsResult};
+    use crate::tvshow::episode_group::EpisodeGroupKind;
+
+    #[tokio::test]
+    async fn it_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/tv/63926/episode_groups")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/tv-episode-groups.json"))
+            .create_async()
+            .await;
+
+        let result = TVShowEpisodeGroups::new(63926)
+            .execute(&client)
+            .await
+            .unwrap();
+        assert_eq!(result.id, 63926);
+        let item = result.results.first().unwrap();
+        assert_eq!(item.kind, EpisodeGroupKind::Absolute);
+        assert_eq!(item.network.as_ref().unwrap().name, "TV Tokyo");
+        let item = result.results.last().unwrap();
+        assert_eq!(item.kind, EpisodeGroupKind::Dvd);
+        assert!(item.description.is_none());
+    }
+
+    #[test]
+    fn with_unknown_values() {
+        let mut value: serde_json::Value =
+            serde_json::from_str(include_str!("../../assets/tv-episode-groups.json")).unwrap();
+        value["results"][0]["type"] = 8.into();
+        let result: TVShowEpisodeGroupsResult = serde_json::from_value(value).unwrap();
+        let item = result.results.first().unwrap();
+        assert_eq!(item.kind, EpisodeGroupKind::Unknown(8));
+        let item = result.results.last().unwrap();
+        assert_eq!(item.kind, EpisodeGroupKind::Dvd);
+    }
+
+    #[tokio::test]
+    async fn invalid_api_key() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/tv/63926/episode_groups")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(401)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/invalid-api-key.json"))
+            .create_async()
+            .await;
+
+        let err = TVShowEpisodeGroups::new(63926)
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 7);
+    }
+
+    #[tokio::test]
+    async fn resource_not_found() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/tv/63926/episode_groups")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/resource-not-found.json"))
+            .create_async()
+            .await;
+
+        let err = TVShowEpisodeGroups::new(63926)
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 34);
+    }
+}
+
+#[cfg(all(test, feature = "integration"))]
+mod integration_tests {
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+    use crate::prelude::Command;
+
+    use super::TVShowEpisodeGroups;
+
+    #[tokio::test]
+    async fn execute() {
+        let secret = std::env::var("TMDB_TOKEN_V3").unwrap();
+        let client = Client::<ReqwestExecutor>::new(secret);
+
+        let result = TVShowEpisodeGroups::new(63926)
+            .execute(&client)
+            .await
+            .unwrap();
+        assert_eq!(result.id, 63926);
+    }
+}
@@ -1,5 +1,6 @@
 use std::borrow::Cow;
 
+use crate::common::id::TvId;
 use crate::common::image::Image;
 
 /// Get the images that belong to a show.
@@ -24,15 +25,15 @@ use crate::common::image::Image;
 #[derive(Clone, Debug, Default)]
 pub struct TVShowImages {
     /// ID of the show
-    pub tvshow_id: u64,
+    pub tvshow_id: TvId,
     /// ISO 639-1 value to display translated data for the fields that support it.
     pub language: Option<String>,
 }
 
 impl TVShowImages {
-    pub fn new(tvshow_id: u64) -> Self {
+    pub fn new(tvshow_id: impl Into<TvId>) -> Self {
         Self {
-            tvshow_id,
+            tvshow_id: tvshow_id.into(),
             language: None,
         }
     }
@@ -45,7 +46,7 @@ impl TVShowImages {
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct TVShowImagesResult {
-    pub id: u64,
+    pub id: TvId,
     pub backdrops: Vec<Image>,
     pub posters: Vec<Image>,
     pub logos: Vec<Image>,
@@ -1,5 +1,6 @@
 use std::borrow::Cow;
 
+use crate::common::id::TvId;
 use crate::common::keyword::Keyword;
 
 /// Get the keywords that have been added to a tv.
@@ -24,18 +25,20 @@ use crate::common::keyword::Keyword;
 #[derive(Clone, Debug, Default)]
 pub struct TVShowKeywords {
     /// ID of the show
-    pub series_id: u64,
+    pub series_id: TvId,
 }
 
 impl TVShowKeywords {
-    pub fn new(series_id: u64) -> Self {
-        Self { series_id }
+    pub fn new(series_id: impl Into<TvId>) -> Self {
+        Self {
+            series_id: series_id.into(),
+        }
     }
 }
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct TVShowKeywordsResult {
-    pub id: u64,
+    pub id: TvId,
     pub results: Vec<Keyword>,
 }
 
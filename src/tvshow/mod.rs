@@ -1,44 +1,90 @@
 #[cfg(feature = "commands")]
+pub mod account_states;
+#[cfg(feature = "commands")]
+pub mod add_rating;
+#[cfg(feature = "commands")]
+pub mod aggregate_credits;
+#[cfg(feature = "commands")]
+pub mod airing_today;
+#[cfg(feature = "commands")]
+pub mod alternative_titles;
+#[cfg(feature = "commands")]
+pub mod changes;
+#[cfg(feature = "commands")]
 pub mod content_ratings;
 #[cfg(feature = "commands")]
 pub mod credits;
 #[cfg(feature = "commands")]
+pub mod delete_rating;
+#[cfg(feature = "commands")]
 pub mod details;
 #[cfg(feature = "commands")]
 pub mod discover;
 #[cfg(feature = "commands")]
+pub mod episode_groups;
+#[cfg(feature = "commands")]
+pub mod external_ids;
+#[cfg(feature = "commands")]
 pub mod images;
 #[cfg(feature = "commands")]
 pub mod keywords;
 #[cfg(feature = "commands")]
 pub mod latest;
 #[cfg(feature = "commands")]
+pub mod lists;
+#[cfg(feature = "commands")]
+pub mod on_the_air;
+#[cfg(feature = "commands")]
 pub mod popular;
 #[cfg(feature = "commands")]
+pub mod recommendations;
+#[cfg(feature = "commands")]
+pub mod reviews;
+#[cfg(feature = "commands")]
+pub mod screened_theatrically;
+#[cfg(feature = "commands")]
 pub mod search;
 #[cfg(feature = "commands")]
+pub mod series_tree;
+#[cfg(feature = "commands")]
 pub mod similar;
 #[cfg(feature = "commands")]
+pub mod top_rated;
+#[cfg(feature = "commands")]
+pub mod translations;
+#[cfg(feature = "commands")]
 pub mod trending;
 #[cfg(feature = "commands")]
 pub mod videos;
 #[cfg(feature = "commands")]
 pub mod watch_providers;
-#[cfg(feature = "commands")]
-pub mod translations;
 
 pub mod episode;
+pub mod episode_group;
 pub mod season;
 
 use crate::common::country::Country;
+use crate::common::credits::{Cast, Crew, CrewCredit, CrewList};
+use crate::common::department::Department;
+use crate::common::id::{EpisodeId, NetworkId, TvId};
 use crate::common::language::Language;
 use crate::company::CompanyShort;
 use crate::genre::Genre;
 use crate::people::PersonShort;
 
+/// Network broadcasting a tv show.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NetworkShort {
+    pub id: NetworkId,
+    pub name: String,
+    pub logo_path: Option<String>,
+    #[serde(deserialize_with = "crate::util::empty_string::deserialize")]
+    pub origin_country: Option<String>,
+}
+
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct TVShowBase {
-    pub id: u64,
+    pub id: TvId,
     pub name: String,
     pub original_name: String,
     pub original_language: String,
@@ -70,7 +116,7 @@ pub struct EpisodeShort {
     #[serde(deserialize_with = "crate::util::empty_string::deserialize")]
     pub air_date: Option<chrono::NaiveDate>,
     pub episode_number: u64,
-    pub id: u64,
+    pub id: EpisodeId,
     pub name: String,
     #[serde(deserialize_with = "crate::util::empty_string::deserialize")]
     pub overview: Option<String>,
@@ -81,13 +127,49 @@ pub struct EpisodeShort {
     pub vote_count: u64,
 }
 
+/// Crew member working on a tv show episode.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct EpisodeCrew {
+    #[serde(flatten)]
+    pub inner: Crew,
+}
+
+impl CrewCredit for EpisodeCrew {
+    fn department(&self) -> &Department {
+        self.inner.department()
+    }
+
+    fn has_job<F: Fn(&str) -> bool>(&self, predicate: F) -> bool {
+        self.inner.has_job(predicate)
+    }
+}
+
+/// Actor appearing in a tv show episode without being part of the regular cast.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GuestStar {
+    #[serde(flatten)]
+    pub inner: Cast,
+}
+
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct Episode {
     #[serde(flatten)]
     pub inner: EpisodeShort,
     //
-    pub crew: Vec<PersonShort>,
-    pub guest_stars: Vec<PersonShort>,
+    pub crew: Vec<EpisodeCrew>,
+    pub guest_stars: Vec<GuestStar>,
+}
+
+impl Episode {
+    /// Crew members who directed the episode.
+    pub fn directors(&self) -> Vec<&EpisodeCrew> {
+        self.crew.directors()
+    }
+
+    /// Crew members who wrote the episode, whatever the writing job.
+    pub fn writers(&self) -> Vec<&EpisodeCrew> {
+        self.crew.writers()
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
@@ -118,6 +200,98 @@ pub struct Season {
     pub episodes: Vec<Episode>,
 }
 
+/// Production status of a tv show.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum TVShowStatus {
+    #[serde(rename = "Returning Series")]
+    ReturningSeries,
+    Planned,
+    #[serde(rename = "In Production")]
+    InProduction,
+    Ended,
+    Canceled,
+    Pilot,
+    /// Status not known by this crate, with its raw value.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl TVShowStatus {
+    /// Code of the status in the discover filters, unknown for the unknown statuses.
+    pub fn code(&self) -> Option<u8> {
+        match self {
+            Self::ReturningSeries => Some(0),
+            Self::Planned => Some(1),
+            Self::InProduction => Some(2),
+            Self::Ended => Some(3),
+            Self::Canceled => Some(4),
+            Self::Pilot => Some(5),
+            Self::Unknown(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for TVShowStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ReturningSeries => f.write_str("Returning Series"),
+            Self::Planned => f.write_str("Planned"),
+            Self::InProduction => f.write_str("In Production"),
+            Self::Ended => f.write_str("Ended"),
+            Self::Canceled => f.write_str("Canceled"),
+            Self::Pilot => f.write_str("Pilot"),
+            Self::Unknown(value) => f.write_str(value),
+        }
+    }
+}
+
+/// Kind of a tv show.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum TVShowType {
+    Scripted,
+    Reality,
+    Documentary,
+    News,
+    #[serde(rename = "Talk Show")]
+    TalkShow,
+    Miniseries,
+    Video,
+    /// Type not known by this crate, with its raw value.
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl TVShowType {
+    /// Code of the type in the discover filters, unknown for the unknown types.
+    pub fn code(&self) -> Option<u8> {
+        match self {
+            Self::Documentary => Some(0),
+            Self::News => Some(1),
+            Self::Miniseries => Some(2),
+            Self::Reality => Some(3),
+            Self::Scripted => Some(4),
+            Self::TalkShow => Some(5),
+            Self::Video => Some(6),
+            Self::Unknown(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for TVShowType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Scripted => f.write_str("Scripted"),
+            Self::Reality => f.write_str("Reality"),
+            Self::Documentary => f.write_str("Documentary"),
+            Self::News => f.write_str("News"),
+            Self::TalkShow => f.write_str("Talk Show"),
+            Self::Miniseries => f.write_str("Miniseries"),
+            Self::Video => f.write_str("Video"),
+            Self::Unknown(value) => f.write_str(value),
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct TVShow {
     #[serde(flatten)]
@@ -132,7 +306,7 @@ pub struct TVShow {
     pub last_air_date: Option<chrono::NaiveDate>,
     pub last_episode_to_air: Option<EpisodeShort>,
     pub next_episode_to_air: Option<EpisodeShort>,
-    pub networks: Vec<CompanyShort>,
+    pub networks: Vec<NetworkShort>,
     /// Unlikely to be `None` but found with 81040.
     /// In this case, could be computed by summing the `episodes_count` of the `seasons` field.
     pub number_of_episodes: Option<u64>,
@@ -141,9 +315,33 @@ pub struct TVShow {
     pub production_countries: Vec<Country>,
     pub seasons: Vec<SeasonShort>,
     pub spoken_languages: Vec<Language>,
-    pub status: String,
+    pub status: TVShowStatus,
     #[serde(deserialize_with = "crate::util::empty_string::deserialize")]
     pub tagline: Option<String>,
     #[serde(rename = "type")]
-    pub ttype: String,
+    pub ttype: TVShowType,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{TVShowStatus, TVShowType};
+
+    #[test]
+    fn should_deserialize_status() {
+        let value: TVShowStatus = serde_json::from_str(r#""Returning Series""#).unwrap();
+        assert_eq!(value, TVShowStatus::ReturningSeries);
+        let value: TVShowStatus = serde_json::from_str(r#""On Hiatus""#).unwrap();
+        assert_eq!(value, TVShowStatus::Unknown("On Hiatus".into()));
+        assert_eq!(serde_json::to_string(&value).unwrap(), r#""On Hiatus""#);
+        assert_eq!(value.to_string(), "On Hiatus");
+    }
+
+    #[test]
+    fn should_deserialize_type() {
+        let value: TVShowType = serde_json::from_str(r#""Talk Show""#).unwrap();
+        assert_eq!(value, TVShowType::TalkShow);
+        assert_eq!(serde_json::to_string(&value).unwrap(), r#""Talk Show""#);
+        let value: TVShowType = serde_json::from_str(r#""Animation""#).unwrap();
+        assert_eq!(value, TVShowType::Unknown("Animation".into()));
+    }
 }
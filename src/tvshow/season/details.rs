@@ -1,5 +1,7 @@
 use std::borrow::Cow;
 
+use crate::common::id::TvId;
+
 /// Command to get the details of a tvshow season
 ///
 /// ```rust
@@ -22,7 +24,7 @@ use std::borrow::Cow;
 #[derive(Clone, Debug, Default)]
 pub struct TVShowSeasonDetails {
     /// ID of the TV Show
-    pub tv_id: u64,
+    pub tv_id: TvId,
     /// Number of the season
     pub season_number: u64,
     /// ISO 639-1 value to display translated data for the fields that support it.
@@ -30,9 +32,9 @@ pub struct TVShowSeasonDetails {
 }
 
 impl TVShowSeasonDetails {
-    pub fn new(tv_id: u64, season_number: u64) -> Self {
+    pub fn new(tv_id: impl Into<TvId>, season_number: u64) -> Self {
         Self {
-            tv_id,
+            tv_id: tv_id.into(),
             season_number,
             language: None,
         }
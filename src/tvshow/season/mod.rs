@@ -1,2 +1,20 @@
 #[cfg(feature = "commands")]
+pub mod account_states;
+#[cfg(feature = "commands")]
+pub mod aggregate_credits;
+#[cfg(feature = "commands")]
+pub mod changes;
+#[cfg(feature = "commands")]
+pub mod credits;
+#[cfg(feature = "commands")]
 pub mod details;
+#[cfg(feature = "commands")]
+pub mod external_ids;
+#[cfg(feature = "commands")]
+pub mod images;
+#[cfg(feature = "commands")]
+pub mod translations;
+#[cfg(feature = "commands")]
+pub mod videos;
+#[cfg(feature = "commands")]
+pub mod watch_providers;
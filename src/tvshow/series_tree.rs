@@ -0,0 +1,623 @@
+use std::borrow::Cow;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::Poll;
+
+use crate::client::{Client, Executor};
+use crate::common::id::TvId;
+use crate::prelude::Command;
+
+use super::details::TVShowDetails;
+use super::season::details::TVShowSeasonDetails;
+use super::{Episode, Season, TVShow};
+
+/// Default number of requests running at the same time.
+pub const DEFAULT_CONCURRENCY: usize = 4;
+
+/// Highest number of items TMDB accepts in `append_to_response`.
+pub const MAX_APPENDED_SEASONS: usize = 20;
+
+type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// Runs the futures with at most `limit` of them in flight and returns their outputs in order.
+///
+/// Stops at the first error, dropping the futures in flight, which cancels their requests.
+async fn try_join_bounded<'a, T, E>(
+    futures: Vec<BoxFuture<'a, Result<T, E>>>,
+    limit: usize,
+) -> Result<Vec<T>, E> {
+    let mut results: Vec<Option<T>> = futures.iter().map(|_| None).collect();
+    let mut pending = futures.into_iter().enumerate();
+    let mut running: Vec<(usize, BoxFuture<'a, Result<T, E>>)> = Vec::with_capacity(limit);
+
+    std::future::poll_fn(|cx| loop {
+        while running.len() < limit {
+            match pending.next() {
+                Some(item) => running.push(item),
+                None => break,
+            }
+        }
+        if running.is_empty() {
+            return Poll::Ready(Ok(()));
+        }
+        let mut progressed = false;
+        let mut index = 0;
+        while index < running.len() {
+            if let Poll::Ready(output) = running[index].1.as_mut().poll(cx) {
+                let (position, _) = running.swap_remove(index);
+                match output {
+                    Ok(value) => results[position] = Some(value),
+                    Err(err) => {
+                        running.clear();
+                        return Poll::Ready(Err(err));
+                    }
+                }
+                progressed = true;
+            } else {
+                index += 1;
+            }
+        }
+        if !progressed {
+            return Poll::Pending;
+        }
+    })
+    .await?;
+
+    Ok(results.into_iter().flatten().collect())
+}
+
+/// TV show along with the details of all its seasons and their episodes.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct SeriesTree {
+    pub show: TVShow,
+    /// Seasons, ordered by season number.
+    pub seasons: Vec<Season>,
+}
+
+impl SeriesTree {
+    pub fn season(&self, season_number: u64) -> Option<&Season> {
+        self.seasons
+            .iter()
+            .find(|item| item.inner.season_number == season_number)
+    }
+
+    pub fn episodes(&self) -> impl Iterator<Item = &Episode> {
+        self.seasons.iter().flat_map(|item| item.episodes.iter())
+    }
+}
+
+/// Details of a tv show with some of its seasons appended to the response.
+#[derive(Clone, Debug)]
+struct TVShowDetailsWithSeasons {
+    tv_id: TvId,
+    language: Option<String>,
+    append_to_response: String,
+}
+
+impl TVShowDetailsWithSeasons {
+    fn new(tv_id: TvId, language: Option<String>, season_numbers: &[u64]) -> Self {
+        Self {
+            tv_id,
+            language,
+            append_to_response: season_numbers
+                .iter()
+                .map(|season_number| format!("season/{season_number}"))
+                .collect::<Vec<_>>()
+                .join(","),
+        }
+    }
+}
+
+impl Command for TVShowDetailsWithSeasons {
+    type Output = serde_json::Value;
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Owned(format!("/tv/{}", self.tv_id))
+    }
+
+    fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
+        let mut res = vec![(
+            "append_to_response",
+            Cow::Borrowed(self.append_to_response.as_str()),
+        )];
+        if let Some(language) = self.language.as_ref() {
+            res.push(("language", Cow::Borrowed(language.as_str())));
+        }
+        res
+    }
+}
+
+fn decoding_error(err: serde_json::Error) -> crate::error::Error {
+    crate::error::Error::Response {
+        source: Box::new(err),
+    }
+}
+
+fn sorted_season_numbers(show: &TVShow) -> Vec<u64> {
+    let mut season_numbers: Vec<u64> = show
+        .seasons
+        .iter()
+        .map(|item| item.inner.season_number)
+        .collect();
+    season_numbers.sort_unstable();
+    season_numbers.dedup();
+    season_numbers
+}
+
+/// Removes the season appended to the tv show details and decodes it.
+fn take_appended_season(
+    value: &mut serde_json::Value,
+    season_number: u64,
+) -> Result<Season, crate::error::Error> {
+    let key = format!("season/{season_number}");
+    let season = value
+        .as_object_mut()
+        .and_then(|object| object.remove(&key))
+        .ok_or_else(|| {
+            <serde_json::Error as serde::de::Error>::custom(format!("missing field `{key}`"))
+        })
+        .map_err(decoding_error)?;
+    serde_json::from_value(season).map_err(decoding_error)
+}
+
+/// Fetches a tv show, then the details of all its seasons.
+///
+/// The seasons are fetched with a bounded number of requests running at the same time.
+/// With `append_to_response`, seasons 0 to 19 come with the tv show details, and the others
+/// are fetched by 20 with each request instead of one.
+///
+/// ```rust
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::tvshow::series_tree::SeriesTreeFetcher;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into());
+///     let result = SeriesTreeFetcher::new(1399)
+///         .with_concurrency(2)
+///         .with_append_to_response(true)
+///         .fetch(&client)
+///         .await;
+///     match result {
+///         Ok(res) => println!("found {} episodes", res.episodes().count()),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct SeriesTreeFetcher {
+    /// ID of the TV Show
+    pub tv_id: TvId,
+    /// ISO 639-1 value to display translated data for the fields that support it.
+    pub language: Option<String>,
+    /// Highest number of requests running at the same time.
+    pub concurrency: usize,
+    /// Whether to fetch the seasons by batches, appended to the tv show details.
+    pub append_to_response: bool,
+}
+
+impl SeriesTreeFetcher {
+    pub fn new(tv_id: impl Into<TvId>) -> Self {
+        Self {
+            tv_id: tv_id.into(),
+            language: None,
+            concurrency: DEFAULT_CONCURRENCY,
+            append_to_response: false,
+        }
+    }
+
+    pub fn with_language(mut self, value: Option<String>) -> Self {
+        self.language = value;
+        self
+    }
+
+    pub fn with_concurrency(mut self, value: usize) -> Self {
+        self.concurrency = value.max(1);
+        self
+    }
+
+    pub fn with_append_to_response(mut self, value: bool) -> Self {
+        self.append_to_response = value;
+        self
+    }
+
+    async fn fetch_seasons<E: Executor + Send + Sync>(
+        &self,
+        client: &Client<E>,
+        season_numbers: &[u64],
+    ) -> Result<Vec<Season>, crate::error::Error> {
+        let futures: Vec<BoxFuture<'_, _>> = season_numbers
+            .iter()
+            .map(|season_number| {
+                let cmd = TVShowSeasonDetails::new(self.tv_id, *season_number)
+                    .with_language(self.language.clone());
+                Box::pin(async move { cmd.execute(client).await }) as BoxFuture<'_, _>
+            })
+            .collect();
+        try_join_bounded(futures, self.concurrency).await
+    }
+
+    async fn fetch_appended_seasons<E: Executor + Send + Sync>(
+        &self,
+        client: &Client<E>,
+        season_numbers: &[u64],
+    ) -> Result<Vec<Season>, crate::error::Error> {
+        let futures: Vec<BoxFuture<'_, _>> = season_numbers
+            .chunks(MAX_APPENDED_SEASONS)
+            .map(|chunk| {
+                let cmd = TVShowDetailsWithSeasons::new(self.tv_id, self.language.clone(), chunk);
+                Box::pin(async move {
+                    let mut value = cmd.execute(client).await?;
+                    chunk
+                        .iter()
+                        .map(|season_number| take_appended_season(&mut value, *season_number))
+                        .collect::<Result<Vec<_>, _>>()
+                }) as BoxFuture<'_, _>
+            })
+            .collect();
+        let chunks = try_join_bounded(futures, self.concurrency).await?;
+        Ok(chunks.into_iter().flatten().collect())
+    }
+
+    /// Fetches the tv show details with the first seasons appended, before the season numbers
+    /// are known, then the seasons numbered beyond them.
+    async fn fetch_appended<E: Executor + Send + Sync>(
+        &self,
+        client: &Client<E>,
+    ) -> Result<SeriesTree, crate::error::Error> {
+        let first_numbers: Vec<u64> = (0..MAX_APPENDED_SEASONS as u64).collect();
+        let mut value =
+            TVShowDetailsWithSeasons::new(self.tv_id, self.language.clone(), &first_numbers)
+                .execute(client)
+                .await?;
+        let mut appended = serde_json::Map::new();
+        if let Some(object) = value.as_object_mut() {
+            for season_number in first_numbers.iter() {
+                let key = format!("season/{season_number}");
+                if let Some(season) = object.remove(&key) {
+                    appended.insert(key, season);
+                }
+            }
+        }
+        let show: TVShow = serde_json::from_value(value).map_err(decoding_error)?;
+        let season_numbers = sorted_season_numbers(&show);
+        let (first, rest): (Vec<u64>, Vec<u64>) = season_numbers
+            .into_iter()
+            .partition(|season_number| *season_number < MAX_APPENDED_SEASONS as u64);
+
+        let mut appended = serde_json::Value::Object(appended);
+        let mut seasons = first
+            .into_iter()
+            .map(|season_number| take_appended_season(&mut appended, season_number))
+            .collect::<Result<Vec<_>, _>>()?;
+        seasons.extend(self.fetch_appended_seasons(client, &rest).await?);
+        Ok(SeriesTree { show, seasons })
+    }
+
+    pub async fn fetch<E: Executor + Send + Sync>(
+        &self,
+        client: &Client<E>,
+    ) -> Result<SeriesTree, crate::error::Error> {
+        if self.append_to_response {
+            return self.fetch_appended(client).await;
+        }
+        let show = TVShowDetails::new(self.tv_id)
+            .with_language(self.language.clone())
+            .execute(client)
+            .await?;
+        let season_numbers = sorted_season_numbers(&show);
+        let seasons = self.fetch_seasons(client, &season_numbers).await?;
+        Ok(SeriesTree { show, seasons })
+    }
+}
+
+/// Fetches a tv show with all its seasons and episodes, with the default settings
+/// of [`SeriesTreeFetcher`].
+pub async fn fetch_series_tree<E: Executor + Send + Sync>(
+    client: &Client<E>,
+    tv_id: impl Into<TvId>,
+) -> Result<SeriesTree, crate::error::Error> {
+    SeriesTreeFetcher::new(tv_id).fetch(client).await
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::Client;
+
+    use super::{fetch_series_tree, try_join_bounded, BoxFuture, SeriesTreeFetcher};
+
+    #[tokio::test]
+    async fn should_run_every_future_beyond_the_limit() {
+        let futures: Vec<BoxFuture<'_, Result<usize, ()>>> = (0..5usize)
+            .map(|index| Box::pin(async move { Ok(index) }) as BoxFuture<'_, _>)
+            .collect();
+        let result = try_join_bounded(futures, 2).await.unwrap();
+        assert_eq!(result, vec![0, 1, 2, 3, 4]);
+    }
+
+    #[tokio::test]
+    async fn should_stop_at_the_first_error() {
+        let futures: Vec<BoxFuture<'_, Result<usize, usize>>> = vec![
+            Box::pin(std::future::pending()),
+            Box::pin(async { Err(1) }),
+            Box::pin(std::future::pending()),
+        ];
+        let result = try_join_bounded(futures, 2).await;
+        assert_eq!(result, Err(1));
+    }
+
+    #[tokio::test]
+    async fn should_fetch_more_seasons_than_the_concurrency() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _details = server
+            .mock("GET", "/tv/1399")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/tv-details.json"))
+            .create_async()
+            .await;
+        let _seasons = server
+            .mock("GET", Matcher::Regex(r"^/tv/1399/season/\d+$".into()))
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body_from_request(|req| {
+                let path = req.path_and_query().split('?').next().unwrap();
+                let season_number: u64 = path.rsplit('/').next().unwrap().parse().unwrap();
+                let mut season: serde_json::Value =
+                    serde_json::from_str(include_str!("../../assets/tv-season-details.json"))
+                        .unwrap();
+                season["season_number"] = season_number.into();
+                serde_json::to_vec(&season).unwrap()
+            })
+            .create_async()
+            .await;
+
+        let result = SeriesTreeFetcher::new(1399)
+            .with_concurrency(2)
+            .fetch(&client)
+            .await
+            .unwrap();
+        let season_numbers: Vec<u64> = result
+            .seasons
+            .iter()
+            .map(|season| season.inner.season_number)
+            .collect();
+        assert_eq!(season_numbers, (0..=8).collect::<Vec<_>>());
+    }
+
+    #[tokio::test]
+    async fn should_name_the_missing_appended_season() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _details = server
+            .mock("GET", "/tv/1399")
+            .match_query(Matcher::Any)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/tv-details.json"))
+            .create_async()
+            .await;
+
+        let err = SeriesTreeFetcher::new(1399)
+            .with_append_to_response(true)
+            .fetch(&client)
+            .await
+            .unwrap_err();
+        match err {
+            crate::error::Error::Response { source } => {
+                assert!(source.to_string().contains("`season/0`"), "{source}")
+            }
+            other => panic!("unexpected error {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn it_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _details = server
+            .mock("GET", "/tv/1399")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/tv-details.json"))
+            .create_async()
+            .await;
+        let seasons = server
+            .mock("GET", Matcher::Regex(r"^/tv/1399/season/\d+$".into()))
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/tv-season-details.json"))
+            .expect(9)
+            .create_async()
+            .await;
+
+        let result = fetch_series_tree(&client, 1399).await.unwrap();
+        seasons.assert_async().await;
+        assert_eq!(result.show.inner.id, 1399);
+        assert_eq!(result.seasons.len(), 9);
+        assert_eq!(result.season(1).unwrap().inner.id, 3624);
+        assert_eq!(result.episodes().count(), 9 * 10);
+    }
+
+    #[tokio::test]
+    async fn should_append_seasons() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let mut body: serde_json::Value =
+            serde_json::from_str(include_str!("../../assets/tv-details.json")).unwrap();
+        let season: serde_json::Value =
+            serde_json::from_str(include_str!("../../assets/tv-season-details.json")).unwrap();
+        for season_number in 0..=8 {
+            body.as_object_mut()
+                .unwrap()
+                .insert(format!("season/{season_number}"), season.clone());
+        }
+
+        let details = server
+            .mock("GET", "/tv/1399")
+            .match_query(Matcher::Exact("api_key=secret".into()))
+            .expect(0)
+            .create_async()
+            .await;
+        let appended = server
+            .mock("GET", "/tv/1399")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("api_key".into(), "secret".into()),
+                Matcher::UrlEncoded(
+                    "append_to_response".into(),
+                    (0..20)
+                        .map(|season_number| format!("season/{season_number}"))
+                        .collect::<Vec<_>>()
+                        .join(","),
+                ),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(body.to_string())
+            .expect(1)
+            .create_async()
+            .await;
+        let seasons = server
+            .mock("GET", Matcher::Regex(r"^/tv/1399/season/\d+$".into()))
+            .expect(0)
+            .create_async()
+            .await;
+
+        let result = SeriesTreeFetcher::new(1399)
+            .with_append_to_response(true)
+            .fetch(&client)
+            .await
+            .unwrap();
+        details.assert_async().await;
+        appended.assert_async().await;
+        seasons.assert_async().await;
+        assert_eq!(result.seasons.len(), 9);
+    }
+
+    #[tokio::test]
+    async fn should_append_the_seasons_beyond_the_first_request() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let mut details: serde_json::Value =
+            serde_json::from_str(include_str!("../../assets/tv-details.json")).unwrap();
+        details["seasons"][8]["season_number"] = 25.into();
+        let season: serde_json::Value =
+            serde_json::from_str(include_str!("../../assets/tv-season-details.json")).unwrap();
+        let mut first = details.clone();
+        let numbered = |season_number: u64| {
+            let mut season = season.clone();
+            season["season_number"] = season_number.into();
+            season
+        };
+        for season_number in 0..=7 {
+            first
+                .as_object_mut()
+                .unwrap()
+                .insert(format!("season/{season_number}"), numbered(season_number));
+        }
+        let mut rest = details;
+        rest.as_object_mut()
+            .unwrap()
+            .insert("season/25".into(), numbered(25));
+
+        let _first = server
+            .mock("GET", "/tv/1399")
+            .match_query(Matcher::Regex("append_to_response=season%2F0".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(first.to_string())
+            .expect(1)
+            .create_async()
+            .await;
+        let rest = server
+            .mock("GET", "/tv/1399")
+            .match_query(Matcher::UrlEncoded(
+                "append_to_response".into(),
+                "season/25".into(),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(rest.to_string())
+            .expect(1)
+            .create_async()
+            .await;
+
+        let result = SeriesTreeFetcher::new(1399)
+            .with_append_to_response(true)
+            .fetch(&client)
+            .await
+            .unwrap();
+        rest.assert_async().await;
+        let season_numbers: Vec<u64> = result
+            .seasons
+            .iter()
+            .map(|season| season.inner.season_number)
+            .collect();
+        assert_eq!(season_numbers, vec![0, 1, 2, 3, 4, 5, 6, 7, 25]);
+    }
+
+    #[tokio::test]
+    async fn resource_not_found() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _details = server
+            .mock("GET", "/tv/1399")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/tv-details.json"))
+            .create_async()
+            .await;
+        let _seasons = server
+            .mock("GET", Matcher::Regex(r"^/tv/1399/season/\d+$".into()))
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/resource-not-found.json"))
+            .create_async()
+            .await;
+
+        let err = fetch_series_tree(&client, 1399).await.unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 34);
+    }
+}
@@ -1,5 +1,7 @@
 use std::borrow::Cow;
 
+use crate::common::id::TvId;
+
 /// Command to get similar tvshows
 ///
 /// ```rust
@@ -22,7 +24,7 @@ use std::borrow::Cow;
 #[derive(Clone, Debug, Default)]
 pub struct GetSimilarTVShows {
     /// ID of the tvshow
-    pub tvshow_id: u64,
+    pub tvshow_id: TvId,
     /// ISO 639-1 value to display translated data for the fields that support it.
     pub language: Option<String>,
     /// Which page to query.
@@ -30,9 +32,9 @@ pub struct GetSimilarTVShows {
 }
 
 impl GetSimilarTVShows {
-    pub fn new(tvshow_id: u64) -> Self {
+    pub fn new(tvshow_id: impl Into<TvId>) -> Self {
         Self {
-            tvshow_id,
+            tvshow_id: tvshow_id.into(),
             language: None,
             page: None,
         }
@@ -1,5 +1,7 @@
 use std::borrow::Cow;
 
+use crate::common::id::TvId;
+
 /// Get a list of translations that have been created for a tv.
 ///
 /// ```rust
@@ -22,12 +24,14 @@ use std::borrow::Cow;
 #[derive(Clone, Debug, Default)]
 pub struct TVShowTranslations {
     /// ID of the tv.
-    pub series_id: u64,
+    pub series_id: TvId,
 }
 
 impl TVShowTranslations {
-    pub fn new(series_id: u64) -> Self {
-        Self { series_id }
+    pub fn new(series_id: impl Into<TvId>) -> Self {
+        Self {
+            series_id: series_id.into(),
+        }
     }
 }
 
@@ -54,7 +58,7 @@ pub struct Translation {
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct TVShowTranslationsResult {
-    pub id: u64,
+    pub id: TvId,
     pub translations: Vec<Translation>,
 }
 
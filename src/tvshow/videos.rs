@@ -1,3 +1,4 @@
+use crate::common::id::TvId;
 use crate::common::video::Video;
 use std::borrow::Cow;
 
@@ -23,15 +24,15 @@ use std::borrow::Cow;
 #[derive(Clone, Debug, Default)]
 pub struct TVShowVideos {
     /// ID of the tv.
-    pub series_id: u64,
+    pub series_id: TvId,
     /// ISO 639-1 value to display translated data for the fields that support it.
     pub language: Option<String>,
 }
 
 impl TVShowVideos {
-    pub fn new(series_id: u64) -> Self {
+    pub fn new(series_id: impl Into<TvId>) -> Self {
         Self {
-            series_id,
+            series_id: series_id.into(),
             language: None,
         }
     }
@@ -44,7 +45,7 @@ impl TVShowVideos {
 
 #[derive(Debug, Deserialize)]
 pub struct TVShowVideosResult {
-    pub id: u64,
+    pub id: TvId,
     pub results: Vec<Video>,
 }
 
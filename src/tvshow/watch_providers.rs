@@ -1,5 +1,6 @@
 use std::borrow::Cow;
 
+use crate::common::id::TvId;
 use crate::watch_provider::WatchProviderResult;
 
 /// Get a list of watch providers for a TV show.
@@ -24,12 +25,14 @@ use crate::watch_provider::WatchProviderResult;
 #[derive(Clone, Debug, Default)]
 pub struct TVShowWatchProviders {
     /// ID of the movie.
-    pub tv_id: u64,
+    pub tv_id: TvId,
 }
 
 impl TVShowWatchProviders {
-    pub fn new(tv_id: u64) -> Self {
-        Self { tv_id }
+    pub fn new(tv_id: impl Into<TvId>) -> Self {
+        Self {
+            tv_id: tv_id.into(),
+        }
     }
 }
 
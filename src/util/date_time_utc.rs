@@ -0,0 +1,53 @@
+//! (De)serializes dates formatted like `2016-08-26 17:04:39 UTC`, as used by the authentication endpoints.
+
+use chrono::{DateTime, NaiveDateTime, Utc};
+use serde::{Deserialize, Deserializer, Serializer};
+
+const FORMAT: &str = "%Y-%m-%d %H:%M:%S UTC";
+
+pub(crate) fn serialize<S>(value: &DateTime<Utc>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&value.format(FORMAT).to_string())
+}
+
+pub(crate) fn deserialize<'de, D>(deserializer: D) -> Result<DateTime<Utc>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = String::deserialize(deserializer)?;
+    NaiveDateTime::parse_from_str(&value, FORMAT)
+        .map(|date| date.and_utc())
+        .map_err(serde::de::Error::custom)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Utc};
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct TestingStruct {
+        #[serde(with = "super")]
+        value: DateTime<Utc>,
+    }
+
+    #[test]
+    fn should_deserialize() {
+        let result: TestingStruct =
+            serde_json::from_str(r#"{"value":"1970-01-01 00:01:00 UTC"}"#).unwrap();
+        assert_eq!(result.value, DateTime::from_timestamp(60, 0).unwrap());
+
+        let result = serde_json::from_str::<TestingStruct>(r#"{"value":"1970-01-01T00:01:00Z"}"#);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_serialize() {
+        let result = serde_json::to_string(&TestingStruct {
+            value: DateTime::from_timestamp(60, 0).unwrap(),
+        })
+        .unwrap();
+        assert_eq!(result, r#"{"value":"1970-01-01 00:01:00 UTC"}"#);
+    }
+}
@@ -1 +1,5 @@
+pub(crate) mod date_time_utc;
 pub(crate) mod empty_string;
+pub(crate) mod number_or_string;
+pub(crate) mod rated;
+pub(crate) mod tagged;
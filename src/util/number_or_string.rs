@@ -0,0 +1,44 @@
+//! Deserializes a number that can be sent either as a json number or as a string.
+
+use std::str::FromStr;
+
+use serde::{Deserialize, Deserializer};
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum NumberOrString<T> {
+    Number(T),
+    String(String),
+}
+
+pub(crate) fn deserialize<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + FromStr,
+    T::Err: std::fmt::Display,
+{
+    match NumberOrString::<T>::deserialize(deserializer)? {
+        NumberOrString::Number(value) => Ok(value),
+        NumberOrString::String(value) => T::from_str(&value).map_err(serde::de::Error::custom),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[derive(Debug, Deserialize)]
+    struct TestingStruct {
+        #[serde(deserialize_with = "super::deserialize")]
+        value: u64,
+    }
+
+    #[test]
+    fn should_deserialize() {
+        let result: TestingStruct = serde_json::from_str(r#"{"value":42}"#).unwrap();
+        assert_eq!(result.value, 42);
+
+        let result: TestingStruct = serde_json::from_str(r#"{"value":"42"}"#).unwrap();
+        assert_eq!(result.value, 42);
+
+        assert!(serde_json::from_str::<TestingStruct>(r#"{"value":"foo"}"#).is_err());
+    }
+}
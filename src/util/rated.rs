@@ -0,0 +1,61 @@
+//! (De)serializes the `rated` field of the account states, that is either `false` or `{"value": 8.0}`.
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[derive(Deserialize, Serialize)]
+#[serde(untagged)]
+enum Rated {
+    Value { value: f32 },
+    Flag(bool),
+}
+
+pub(crate) fn serialize<S>(value: &Option<f32>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match value {
+        Some(value) => Rated::Value { value: *value },
+        None => Rated::Flag(false),
+    }
+    .serialize(serializer)
+}
+
+pub(crate) fn deserialize<'de, D>(deserializer: D) -> Result<Option<f32>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Option::<Rated>::deserialize(deserializer)? {
+        Some(Rated::Value { value }) => Ok(Some(value)),
+        Some(Rated::Flag(_)) | None => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[derive(Debug, Serialize, Deserialize)]
+    struct TestingStruct {
+        #[serde(with = "super")]
+        rated: Option<f32>,
+    }
+
+    #[test]
+    fn should_deserialize() {
+        let result: TestingStruct = serde_json::from_str(r#"{"rated":false}"#).unwrap();
+        assert_eq!(result.rated, None);
+
+        let result: TestingStruct = serde_json::from_str(r#"{"rated":null}"#).unwrap();
+        assert_eq!(result.rated, None);
+
+        let result: TestingStruct = serde_json::from_str(r#"{"rated":{"value":8.5}}"#).unwrap();
+        assert_eq!(result.rated, Some(8.5));
+    }
+
+    #[test]
+    fn should_serialize() {
+        let result = serde_json::to_string(&TestingStruct { rated: None }).unwrap();
+        assert_eq!(result, r#"{"rated":false}"#);
+
+        let result = serde_json::to_string(&TestingStruct { rated: Some(8.5) }).unwrap();
+        assert_eq!(result, r#"{"rated":{"value":8.5}}"#);
+    }
+}
@@ -0,0 +1,51 @@
+//! Deserializes internally tagged values by reading their tag first, so that the errors of the
+//! known variants are reported instead of falling back on an untagged catch-all variant.
+
+use serde::{Deserialize, Deserializer};
+use serde_json::Value;
+
+/// Buffers the value and extracts its tag, when there is one.
+pub(crate) fn deserialize<'de, D>(
+    deserializer: D,
+    tag: &str,
+) -> Result<(Option<String>, Value), D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = Value::deserialize(deserializer)?;
+    let tag = value.get(tag).and_then(Value::as_str).map(String::from);
+    Ok((tag, value))
+}
+
+/// Deserializes a buffered value, reporting its errors with the error type of the outer deserializer.
+pub(crate) fn from_value<T, E>(value: Value) -> Result<T, E>
+where
+    T: serde::de::DeserializeOwned,
+    E: serde::de::Error,
+{
+    serde_json::from_value(value).map_err(E::custom)
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn should_extract_tag() {
+        let (tag, value) = super::deserialize(
+            &mut serde_json::Deserializer::from_str(r#"{"media_type":"movie","id":550}"#),
+            "media_type",
+        )
+        .unwrap();
+        assert_eq!(tag.as_deref(), Some("movie"));
+        assert_eq!(value["id"], 550);
+    }
+
+    #[test]
+    fn should_accept_missing_tag() {
+        let (tag, _) = super::deserialize(
+            &mut serde_json::Deserializer::from_str(r#"{"id":550}"#),
+            "media_type",
+        )
+        .unwrap();
+        assert!(tag.is_none());
+    }
+}
@@ -0,0 +1,160 @@
+use std::borrow::Cow;
+
+use crate::account::SortBy;
+use crate::common::PaginatedResult;
+use crate::movie::MovieShort;
+
+/// Get the list of movies marked as favorite by an account.
+///
+/// ```rust
+/// use tmdb_api::prelude::Command;
+/// use tmdb_api::client::{ApiVersion, Client};
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::v4::account::favorite_movies::AccountFavoriteMovies;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::builder()
+///         .with_api_version(ApiVersion::V4)
+///         .with_api_key("this-is-my-read-access-token".into())
+///         .with_access_token("this-is-the-user-access-token".into())
+///         .build()
+///         .unwrap();
+///     let cmd = AccountFavoriteMovies::new("4bc889XXXXXXXXXXXXXXXXXX".into());
+///     let result = cmd.execute(&client).await;
+///     match result {
+///         Ok(res) => println!("found: {:#?}", res),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct AccountFavoriteMovies {
+    /// Object ID of the account, as returned with the access token.
+    pub account_id: String,
+    /// ISO 639-1 value to display translated data for the fields that support it.
+    pub language: Option<String>,
+    /// Which page to query.
+    pub page: Option<u32>,
+    pub sort_by: Option<SortBy>,
+}
+
+impl AccountFavoriteMovies {
+    pub fn new(account_id: String) -> Self {
+        Self {
+            account_id,
+            language: None,
+            page: None,
+            sort_by: None,
+        }
+    }
+
+    pub fn with_language(mut self, value: Option<String>) -> Self {
+        self.language = value;
+        self
+    }
+
+    pub fn with_page(mut self, value: Option<u32>) -> Self {
+        self.page = value;
+        self
+    }
+
+    pub fn with_sort_by(mut self, value: Option<SortBy>) -> Self {
+        self.sort_by = value;
+        self
+    }
+}
+
+impl crate::prelude::Command for AccountFavoriteMovies {
+    type Output = PaginatedResult<MovieShort>;
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Owned(format!("/account/{}/movie/favorites", self.account_id))
+    }
+
+    fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
+        let mut res = Vec::new();
+        if let Some(ref language) = self.language {
+            res.push(("language", Cow::Borrowed(language.as_str())));
+        }
+        if let Some(page) = self.page {
+            res.push(("page", Cow::Owned(page.to_string())));
+        }
+        if let Some(sort_by) = self.sort_by {
+            res.push(("sort_by", Cow::Owned(sort_by.to_string())));
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+
+    use crate::account::SortBy;
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::{ApiVersion, Client};
+    use crate::prelude::Command;
+
+    use super::AccountFavoriteMovies;
+
+    #[tokio::test]
+    async fn it_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_version(ApiVersion::V4)
+            .with_api_key("secret".into())
+            .with_access_token("user-token".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/account/4bc889XXXXXXXXXXXXXXXXXX/movie/favorites")
+            .match_header("authorization", "Bearer user-token")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("page".into(), "1".into()),
+                Matcher::UrlEncoded("sort_by".into(), "created_at.desc".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../../assets/account-favorite-movies.json"))
+            .create_async()
+            .await;
+
+        let result = AccountFavoriteMovies::new("4bc889XXXXXXXXXXXXXXXXXX".into())
+            .with_page(Some(1))
+            .with_sort_by(Some(SortBy::CreatedAtDesc))
+            .execute(&client)
+            .await
+            .unwrap();
+        assert_eq!(result.page, 1);
+        assert_eq!(result.results.first().unwrap().inner.id, 787699);
+    }
+
+    #[tokio::test]
+    async fn invalid_api_key() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_version(ApiVersion::V4)
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/account/4bc889XXXXXXXXXXXXXXXXXX/movie/favorites")
+            .with_status(401)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../../assets/invalid-api-key.json"))
+            .create_async()
+            .await;
+
+        let err = AccountFavoriteMovies::new("4bc889XXXXXXXXXXXXXXXXXX".into())
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 7);
+    }
+}
@@ -0,0 +1,138 @@
+use std::borrow::Cow;
+
+use crate::common::PaginatedResult;
+use crate::v4::list::ListSummary;
+
+/// Get the lists created by an account, including the private ones.
+///
+/// ```rust
+/// use tmdb_api::prelude::Command;
+/// use tmdb_api::client::{ApiVersion, Client};
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::v4::account::lists::AccountLists;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::builder()
+///         .with_api_version(ApiVersion::V4)
+///         .with_api_key("this-is-my-read-access-token".into())
+///         .with_access_token("this-is-the-user-access-token".into())
+///         .build()
+///         .unwrap();
+///     let cmd = AccountLists::new("4bc889XXXXXXXXXXXXXXXXXX".into());
+///     let result = cmd.execute(&client).await;
+///     match result {
+///         Ok(res) => println!("found: {:#?}", res),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct AccountLists {
+    /// Object ID of the account, as returned with the access token.
+    pub account_id: String,
+    /// Which page to query.
+    pub page: Option<u32>,
+}
+
+impl AccountLists {
+    pub fn new(account_id: String) -> Self {
+        Self {
+            account_id,
+            page: None,
+        }
+    }
+
+    pub fn with_page(mut self, value: Option<u32>) -> Self {
+        self.page = value;
+        self
+    }
+}
+
+impl crate::prelude::Command for AccountLists {
+    type Output = PaginatedResult<ListSummary>;
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Owned(format!("/account/{}/lists", self.account_id))
+    }
+
+    fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
+        let mut res = Vec::new();
+        if let Some(page) = self.page {
+            res.push(("page", Cow::Owned(page.to_string())));
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::{ApiVersion, Client};
+    use crate::prelude::Command;
+
+    use super::AccountLists;
+
+    #[tokio::test]
+    async fn it_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_version(ApiVersion::V4)
+            .with_api_key("secret".into())
+            .with_access_token("user-token".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/account/4bc889XXXXXXXXXXXXXXXXXX/lists")
+            .match_header("authorization", "Bearer user-token")
+            .match_query(Matcher::AllOf(vec![Matcher::UrlEncoded(
+                "page".into(),
+                "1".into(),
+            )]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../../assets/v4-account-lists.json"))
+            .create_async()
+            .await;
+
+        let result = AccountLists::new("4bc889XXXXXXXXXXXXXXXXXX".into())
+            .with_page(Some(1))
+            .execute(&client)
+            .await
+            .unwrap();
+        assert_eq!(result.page, 1);
+        let item = result.results.first().unwrap();
+        assert_eq!(item.id, 8213);
+        assert_eq!(item.number_of_items, 2);
+    }
+
+    #[tokio::test]
+    async fn invalid_api_key() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_version(ApiVersion::V4)
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/account/4bc889XXXXXXXXXXXXXXXXXX/lists")
+            .with_status(401)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../../assets/invalid-api-key.json"))
+            .create_async()
+            .await;
+
+        let err = AccountLists::new("4bc889XXXXXXXXXXXXXXXXXX".into())
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 7);
+    }
+}
@@ -0,0 +1,41 @@
+#[cfg(feature = "commands")]
+pub mod favorite_movies;
+#[cfg(feature = "commands")]
+pub mod favorite_tv;
+#[cfg(feature = "commands")]
+pub mod lists;
+#[cfg(feature = "commands")]
+pub mod rated_movies;
+#[cfg(feature = "commands")]
+pub mod rated_tv;
+#[cfg(feature = "commands")]
+pub mod recommended_movies;
+#[cfg(feature = "commands")]
+pub mod recommended_tv;
+#[cfg(feature = "commands")]
+pub mod watchlist_movies;
+#[cfg(feature = "commands")]
+pub mod watchlist_tv;
+
+use crate::movie::MovieShort;
+use crate::tvshow::TVShowShort;
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct AccountRating {
+    pub value: f32,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct RatedMovie {
+    #[serde(flatten)]
+    pub inner: MovieShort,
+    pub account_rating: AccountRating,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct RatedTVShow {
+    #[serde(flatten)]
+    pub inner: TVShowShort,
+    pub account_rating: AccountRating,
+}
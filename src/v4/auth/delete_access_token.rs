@@ -0,0 +1,113 @@
+use std::borrow::Cow;
+
+use crate::client::Method;
+use crate::common::StatusResult;
+
+/// Command to log out a user access token.
+///
+/// ```rust
+/// use tmdb_api::prelude::Command;
+/// use tmdb_api::client::{ApiVersion, Client};
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::v4::auth::delete_access_token::DeleteAccessToken;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::builder()
+///         .with_api_version(ApiVersion::V4)
+///         .with_api_key("this-is-my-read-access-token".into())
+///         .build()
+///         .unwrap();
+///     let cmd = DeleteAccessToken::new("user-access-token".into());
+///     let result = cmd.execute(&client).await;
+///     match result {
+///         Ok(res) => println!("found: {:#?}", res),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+#[derive(Clone, Default)]
+pub struct DeleteAccessToken {
+    pub access_token: String,
+}
+
+impl std::fmt::Debug for DeleteAccessToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DeleteAccessToken")
+            .field("access_token", &"***")
+            .finish()
+    }
+}
+
+impl DeleteAccessToken {
+    pub fn new(access_token: String) -> Self {
+        Self { access_token }
+    }
+}
+
+impl crate::prelude::Command for DeleteAccessToken {
+    type Output = StatusResult;
+
+    fn method(&self) -> Method {
+        Method::Delete
+    }
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/auth/access_token")
+    }
+
+    fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
+        Vec::new()
+    }
+
+    fn body(&self) -> Option<serde_json::Value> {
+        Some(serde_json::json!({ "access_token": self.access_token }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::{ApiVersion, Client};
+    use crate::prelude::Command;
+
+    use super::DeleteAccessToken;
+
+    #[tokio::test]
+    async fn it_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_version(ApiVersion::V4)
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("DELETE", "/auth/access_token")
+            .match_header("authorization", "Bearer secret")
+            .match_body(Matcher::Json(serde_json::json!({
+                "access_token": "user-token"
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../../assets/v4-status-deleted.json"))
+            .create_async()
+            .await;
+
+        let result = DeleteAccessToken::new("user-token".into())
+            .execute(&client)
+            .await
+            .unwrap();
+        assert!(result.success);
+        assert_eq!(result.status_code, Some(13));
+    }
+
+    #[test]
+    fn should_hide_access_token() {
+        let cmd = DeleteAccessToken::new("user-token".into());
+        assert!(!format!("{cmd:?}").contains("user-token"));
+    }
+}
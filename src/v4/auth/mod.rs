@@ -0,0 +1,25 @@
+#[cfg(feature = "commands")]
+pub mod delete_access_token;
+#[cfg(feature = "commands")]
+pub mod new_access_token;
+#[cfg(feature = "commands")]
+pub mod new_request_token;
+
+use crate::common::StatusResult;
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct RequestToken {
+    #[serde(flatten)]
+    pub status: StatusResult,
+    pub request_token: String,
+}
+
+/// User access token, to use as bearer token for the requests made on behalf of the user.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct AccessToken {
+    #[serde(flatten)]
+    pub status: StatusResult,
+    pub access_token: String,
+    /// Object ID of the account, used by the v4 account commands.
+    pub account_id: String,
+}
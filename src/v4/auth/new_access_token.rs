@@ -0,0 +1,141 @@
+use std::borrow::Cow;
+
+use crate::client::Method;
+
+/// Command to create a user access token from an approved request token.
+///
+/// ```rust
+/// use tmdb_api::prelude::Command;
+/// use tmdb_api::client::{ApiVersion, Client};
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::v4::auth::new_access_token::NewAccessToken;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let mut client = Client::<ReqwestExecutor>::builder()
+///         .with_api_version(ApiVersion::V4)
+///         .with_api_key("this-is-my-read-access-token".into())
+///         .build()
+///         .unwrap();
+///     let cmd = NewAccessToken::new("approved-request-token".into());
+///     match cmd.execute(&client).await {
+///         Ok(res) => client.set_access_token(Some(res.access_token)),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct NewAccessToken {
+    pub request_token: String,
+}
+
+impl NewAccessToken {
+    pub fn new(request_token: String) -> Self {
+        Self { request_token }
+    }
+}
+
+impl crate::prelude::Command for NewAccessToken {
+    type Output = super::AccessToken;
+
+    fn method(&self) -> Method {
+        Method::Post
+    }
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/auth/access_token")
+    }
+
+    fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
+        Vec::new()
+    }
+
+    fn body(&self) -> Option<serde_json::Value> {
+        Some(serde_json::json!({ "request_token": self.request_token }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::{ApiVersion, Client};
+    use crate::prelude::Command;
+
+    use super::NewAccessToken;
+
+    #[tokio::test]
+    async fn it_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_version(ApiVersion::V4)
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("POST", "/auth/access_token")
+            .match_header("authorization", "Bearer secret")
+            .match_body(Matcher::Json(serde_json::json!({
+                "request_token": "request-token"
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../../assets/v4-auth-access-token.json"))
+            .create_async()
+            .await;
+
+        let result = NewAccessToken::new("request-token".into())
+            .execute(&client)
+            .await
+            .unwrap();
+        assert!(result.status.success);
+        assert_eq!(result.account_id, "4bc889XXXXXXXXXXXXXXXXXX");
+    }
+
+    #[tokio::test]
+    async fn should_use_access_token_for_next_commands() {
+        let mut server = mockito::Server::new_async().await;
+        let mut client = Client::<ReqwestExecutor>::builder()
+            .with_api_version(ApiVersion::V4)
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("POST", "/auth/access_token")
+            .match_header("authorization", "Bearer secret")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../../assets/v4-auth-access-token.json"))
+            .create_async()
+            .await;
+
+        let result = NewAccessToken::new("request-token".into())
+            .execute(&client)
+            .await
+            .unwrap();
+        client.set_access_token(Some(result.access_token.clone()));
+
+        let m = server
+            .mock("POST", "/auth/access_token")
+            .match_header(
+                "authorization",
+                format!("Bearer {}", result.access_token).as_str(),
+            )
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../../assets/v4-auth-access-token.json"))
+            .create_async()
+            .await;
+
+        NewAccessToken::new("request-token".into())
+            .execute(&client)
+            .await
+            .unwrap();
+        m.assert_async().await;
+    }
+}
@@ -0,0 +1,134 @@
+use std::borrow::Cow;
+
+use crate::client::Method;
+
+/// Command to create a request token, that the user will need to approve before creating an
+/// access token.
+///
+/// The request token has to be approved at `https://www.themoviedb.org/auth/access?request_token=`.
+///
+/// ```rust
+/// use tmdb_api::prelude::Command;
+/// use tmdb_api::client::{ApiVersion, Client};
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::v4::auth::new_request_token::NewRequestToken;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::builder()
+///         .with_api_version(ApiVersion::V4)
+///         .with_api_key("this-is-my-read-access-token".into())
+///         .build()
+///         .unwrap();
+///     let result = NewRequestToken::default().execute(&client).await;
+///     match result {
+///         Ok(res) => println!("found: {:#?}", res),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct NewRequestToken {
+    /// Where to redirect the user once the request token is approved.
+    pub redirect_to: Option<String>,
+}
+
+impl NewRequestToken {
+    pub fn with_redirect_to(mut self, value: Option<String>) -> Self {
+        self.redirect_to = value;
+        self
+    }
+}
+
+impl crate::prelude::Command for NewRequestToken {
+    type Output = super::RequestToken;
+
+    fn method(&self) -> Method {
+        Method::Post
+    }
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/auth/request_token")
+    }
+
+    fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
+        Vec::new()
+    }
+
+    fn body(&self) -> Option<serde_json::Value> {
+        Some(match self.redirect_to {
+            Some(ref redirect_to) => serde_json::json!({ "redirect_to": redirect_to }),
+            None => serde_json::json!({}),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::{ApiVersion, Client};
+    use crate::prelude::Command;
+
+    use super::NewRequestToken;
+
+    #[tokio::test]
+    async fn it_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_version(ApiVersion::V4)
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("POST", "/auth/request_token")
+            .match_header("authorization", "Bearer secret")
+            .match_query(Matcher::Missing)
+            .match_body(Matcher::Json(serde_json::json!({
+                "redirect_to": "http://localhost/approved"
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../../assets/v4-auth-request-token.json"))
+            .create_async()
+            .await;
+
+        let result = NewRequestToken::default()
+            .with_redirect_to(Some("http://localhost/approved".into()))
+            .execute(&client)
+            .await
+            .unwrap();
+        assert!(result.status.success);
+        assert!(result.request_token.starts_with("eyJhbGciOiJIUzI1NiIs"));
+    }
+
+    #[tokio::test]
+    async fn invalid_api_key() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_version(ApiVersion::V4)
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("POST", "/auth/request_token")
+            .match_header("authorization", "Bearer secret")
+            .with_status(401)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../../assets/invalid-api-key.json"))
+            .create_async()
+            .await;
+
+        let err = NewRequestToken::default()
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 7);
+    }
+}
@@ -0,0 +1,174 @@
+use std::borrow::Cow;
+
+use crate::client::Method;
+
+/// Create a new list owned by the account of the user access token.
+///
+/// ```rust
+/// use tmdb_api::prelude::Command;
+/// use tmdb_api::client::{ApiVersion, Client};
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::v4::list::create::ListCreate;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::builder()
+///         .with_api_version(ApiVersion::V4)
+///         .with_api_key("this-is-my-read-access-token".into())
+///         .with_access_token("this-is-the-user-access-token".into())
+///         .build()
+///         .unwrap();
+///     let cmd = ListCreate::new("Staff picks".into()).with_public(Some(false));
+///     let result = cmd.execute(&client).await;
+///     match result {
+///         Ok(res) => println!("found: {:#?}", res),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ListCreate {
+    pub name: String,
+    pub description: Option<String>,
+    /// ISO 639-1 code of the language of the list.
+    pub language: Option<String>,
+    /// ISO 3166-1 code of the country of the list.
+    pub country: Option<String>,
+    pub public: Option<bool>,
+}
+
+impl ListCreate {
+    pub fn new(name: String) -> Self {
+        Self {
+            name,
+            description: None,
+            language: None,
+            country: None,
+            public: None,
+        }
+    }
+
+    pub fn with_description(mut self, value: Option<String>) -> Self {
+        self.description = value;
+        self
+    }
+
+    pub fn with_language(mut self, value: Option<String>) -> Self {
+        self.language = value;
+        self
+    }
+
+    pub fn with_country(mut self, value: Option<String>) -> Self {
+        self.country = value;
+        self
+    }
+
+    pub fn with_public(mut self, value: Option<bool>) -> Self {
+        self.public = value;
+        self
+    }
+}
+
+impl crate::prelude::Command for ListCreate {
+    type Output = super::ListCreated;
+
+    fn method(&self) -> Method {
+        Method::Post
+    }
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/list")
+    }
+
+    fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
+        Vec::new()
+    }
+
+    fn body(&self) -> Option<serde_json::Value> {
+        let mut body = serde_json::json!({
+            "name": self.name,
+            "iso_639_1": self.language.as_deref().unwrap_or("en"),
+        });
+        if let Some(ref description) = self.description {
+            body["description"] = serde_json::json!(description);
+        }
+        if let Some(ref country) = self.country {
+            body["iso_3166_1"] = serde_json::json!(country);
+        }
+        if let Some(public) = self.public {
+            body["public"] = serde_json::json!(public);
+        }
+        Some(body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::{ApiVersion, Client};
+    use crate::prelude::Command;
+
+    use super::ListCreate;
+
+    #[tokio::test]
+    async fn it_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_version(ApiVersion::V4)
+            .with_api_key("secret".into())
+            .with_access_token("user-token".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("POST", "/list")
+            .match_header("authorization", "Bearer user-token")
+            .match_body(Matcher::Json(serde_json::json!({
+                "name": "Watched together",
+                "iso_639_1": "en",
+                "public": false,
+            })))
+            .with_status(201)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../../assets/v4-list-created.json"))
+            .create_async()
+            .await;
+
+        let result = ListCreate::new("Watched together".into())
+            .with_public(Some(false))
+            .execute(&client)
+            .await
+            .unwrap();
+        assert!(result.status.success);
+        assert_eq!(result.id, 8213);
+    }
+
+    #[tokio::test]
+    async fn invalid_api_key() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_version(ApiVersion::V4)
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("POST", "/list")
+            .with_status(401)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../../assets/invalid-api-key.json"))
+            .create_async()
+            .await;
+
+        let err = ListCreate::new("Watched together".into())
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 7);
+    }
+}
@@ -0,0 +1,187 @@
+use std::borrow::Cow;
+
+/// Get the details of a list, with a page of its items.
+///
+/// Private lists are only visible with the user access token of their owner.
+///
+/// ```rust
+/// use tmdb_api::prelude::Command;
+/// use tmdb_api::client::{ApiVersion, Client};
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::v4::list::details::ListDetails;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::builder()
+///         .with_api_version(ApiVersion::V4)
+///         .with_api_key("this-is-my-read-access-token".into())
+///         .build()
+///         .unwrap();
+///     let result = ListDetails::new(1).execute(&client).await;
+///     match result {
+///         Ok(res) => println!("found: {:#?}", res),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ListDetails {
+    /// ID of the list.
+    pub list_id: u64,
+    /// ISO 639-1 value to display translated data for the fields that support it.
+    pub language: Option<String>,
+    /// Which page of items to query.
+    pub page: Option<u32>,
+    pub sort_by: Option<super::SortBy>,
+}
+
+impl ListDetails {
+    pub fn new(list_id: u64) -> Self {
+        Self {
+            list_id,
+            language: None,
+            page: None,
+            sort_by: None,
+        }
+    }
+
+    pub fn with_language(mut self, value: Option<String>) -> Self {
+        self.language = value;
+        self
+    }
+
+    pub fn with_page(mut self, value: Option<u32>) -> Self {
+        self.page = value;
+        self
+    }
+
+    pub fn with_sort_by(mut self, value: Option<super::SortBy>) -> Self {
+        self.sort_by = value;
+        self
+    }
+}
+
+impl crate::prelude::Command for ListDetails {
+    type Output = super::List;
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Owned(format!("/list/{}", self.list_id))
+    }
+
+    fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
+        let mut res = Vec::new();
+        if let Some(ref language) = self.language {
+            res.push(("language", Cow::Borrowed(language.as_str())));
+        }
+        if let Some(page) = self.page {
+            res.push(("page", Cow::Owned(page.to_string())));
+        }
+        if let Some(sort_by) = self.sort_by {
+            res.push(("sort_by", Cow::Owned(sort_by.to_string())));
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::{ApiVersion, Client};
+    use crate::common::media::MediaShort;
+    use crate::common::MediaType;
+    use crate::prelude::Command;
+    use crate::v4::list::SortBy;
+
+    use super::ListDetails;
+
+    #[tokio::test]
+    async fn it_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_version(ApiVersion::V4)
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/list/8213")
+            .match_header("authorization", "Bearer secret")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("sort_by".into(), "title.asc".into()),
+                Matcher::UrlEncoded("page".into(), "1".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../../assets/v4-list-details.json"))
+            .create_async()
+            .await;
+
+        let result = ListDetails::new(8213)
+            .with_page(Some(1))
+            .with_sort_by(Some(SortBy::TitleAsc))
+            .execute(&client)
+            .await
+            .unwrap();
+        assert_eq!(result.id, 8213);
+        assert_eq!(result.created_by.username, "travisbell");
+        assert_eq!(result.items.total_results, 2);
+        assert!(matches!(result.items.results[0], MediaShort::Movie(_)));
+        assert!(matches!(result.items.results[1], MediaShort::Tv(_)));
+        assert_eq!(
+            result.comment(MediaType::Movie, 787699),
+            Some("Best chocolate ever")
+        );
+        assert_eq!(result.comment(MediaType::Tv, 72879), None);
+    }
+
+    #[tokio::test]
+    async fn should_not_send_api_key() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_version(ApiVersion::V4)
+            .with_api_key("secret".into())
+            .with_access_token("user-token".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let m = server
+            .mock("GET", "/list/8213")
+            .match_header("authorization", "Bearer user-token")
+            .match_query(Matcher::Missing)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../../assets/v4-list-details.json"))
+            .create_async()
+            .await;
+
+        ListDetails::new(8213).execute(&client).await.unwrap();
+        m.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn resource_not_found() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_version(ApiVersion::V4)
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/list/8213")
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../../assets/resource-not-found.json"))
+            .create_async()
+            .await;
+
+        let err = ListDetails::new(8213).execute(&client).await.unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 34);
+    }
+}
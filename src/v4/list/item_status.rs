@@ -0,0 +1,131 @@
+use std::borrow::Cow;
+
+use crate::common::MediaType;
+
+/// Check if a movie or a tv show is in a list.
+///
+/// ```rust
+/// use tmdb_api::prelude::Command;
+/// use tmdb_api::client::{ApiVersion, Client};
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::common::MediaType;
+/// use tmdb_api::v4::list::item_status::ListItemStatus;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::builder()
+///         .with_api_version(ApiVersion::V4)
+///         .with_api_key("this-is-my-read-access-token".into())
+///         .build()
+///         .unwrap();
+///     let cmd = ListItemStatus::new(8213, MediaType::Movie, 550);
+///     let result = cmd.execute(&client).await;
+///     match result {
+///         Ok(res) => println!("found: {:#?}", res),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct ListItemStatus {
+    /// ID of the list.
+    pub list_id: u64,
+    pub media_type: MediaType,
+    pub media_id: u64,
+}
+
+impl ListItemStatus {
+    pub fn new(list_id: u64, media_type: MediaType, media_id: u64) -> Self {
+        Self {
+            list_id,
+            media_type,
+            media_id,
+        }
+    }
+}
+
+impl crate::prelude::Command for ListItemStatus {
+    type Output = super::ItemStatus;
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Owned(format!("/list/{}/item_status", self.list_id))
+    }
+
+    fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
+        vec![
+            ("media_type", Cow::Owned(self.media_type.to_string())),
+            ("media_id", Cow::Owned(self.media_id.to_string())),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::{ApiVersion, Client};
+    use crate::common::MediaType;
+    use crate::prelude::Command;
+
+    use super::ListItemStatus;
+
+    #[tokio::test]
+    async fn it_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_version(ApiVersion::V4)
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/list/8213/item_status")
+            .match_header("authorization", "Bearer secret")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("media_type".into(), "movie".into()),
+                Matcher::UrlEncoded("media_id".into(), "550".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../../assets/v4-list-item-status.json"))
+            .create_async()
+            .await;
+
+        let result = ListItemStatus::new(8213, MediaType::Movie, 550)
+            .execute(&client)
+            .await
+            .unwrap();
+        assert!(result.status.success);
+        assert_eq!(result.media_type, MediaType::Movie);
+        assert_eq!(result.media_id, 550);
+    }
+
+    #[tokio::test]
+    async fn resource_not_found() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_version(ApiVersion::V4)
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/list/8213/item_status")
+            .match_query(Matcher::Any)
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../../assets/resource-not-found.json"))
+            .create_async()
+            .await;
+
+        let err = ListItemStatus::new(8213, MediaType::Tv, 1399)
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 34);
+    }
+}
@@ -0,0 +1,190 @@
+#[cfg(feature = "commands")]
+pub mod add_items;
+#[cfg(feature = "commands")]
+pub mod clear;
+#[cfg(feature = "commands")]
+pub mod create;
+#[cfg(feature = "commands")]
+pub mod delete;
+#[cfg(feature = "commands")]
+pub mod details;
+#[cfg(feature = "commands")]
+pub mod item_status;
+#[cfg(feature = "commands")]
+pub mod remove_items;
+#[cfg(feature = "commands")]
+pub mod update;
+#[cfg(feature = "commands")]
+pub mod update_items;
+
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::common::media::MediaShort;
+use crate::common::{MediaType, PaginatedResult, StatusResult};
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ListAuthor {
+    /// Object ID of the account.
+    pub id: String,
+    pub username: String,
+    #[serde(default, deserialize_with = "crate::util::empty_string::deserialize")]
+    pub name: Option<String>,
+    pub gravatar_hash: Option<String>,
+    pub avatar_path: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct List {
+    pub id: u64,
+    pub name: String,
+    #[serde(default, deserialize_with = "crate::util::empty_string::deserialize")]
+    pub description: Option<String>,
+    pub created_by: ListAuthor,
+    pub iso_639_1: String,
+    pub iso_3166_1: String,
+    pub public: bool,
+    pub poster_path: Option<String>,
+    pub backdrop_path: Option<String>,
+    pub average_rating: Option<f64>,
+    pub item_count: u64,
+    pub revenue: Option<u64>,
+    pub runtime: Option<u64>,
+    pub sort_by: String,
+    /// Comments on the items, indexed by `{media_type}:{media_id}`.
+    #[serde(default)]
+    pub comments: HashMap<String, Option<String>>,
+    /// Page of items of the list.
+    #[serde(flatten)]
+    pub items: PaginatedResult<MediaShort>,
+}
+
+impl List {
+    pub fn comment(&self, media_type: MediaType, media_id: u64) -> Option<&str> {
+        self.comments
+            .get(&format!("{media_type}:{media_id}"))
+            .and_then(|value| value.as_deref())
+    }
+}
+
+/// List as returned in the lists of an account.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ListSummary {
+    pub id: u64,
+    pub name: String,
+    #[serde(default, deserialize_with = "crate::util::empty_string::deserialize")]
+    pub description: Option<String>,
+    pub iso_639_1: String,
+    pub iso_3166_1: String,
+    pub poster_path: Option<String>,
+    pub backdrop_path: Option<String>,
+    pub average_rating: Option<f64>,
+    pub number_of_items: u64,
+    pub revenue: Option<u64>,
+    pub runtime: Option<u64>,
+    #[serde(with = "crate::util::date_time_utc")]
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    #[serde(with = "crate::util::date_time_utc")]
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ListCreated {
+    #[serde(flatten)]
+    pub status: StatusResult,
+    pub id: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ListCleared {
+    #[serde(flatten)]
+    pub status: StatusResult,
+    pub id: u64,
+    pub items_deleted: u64,
+}
+
+/// Item to add, update or remove from a list.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ListItem {
+    pub media_type: MediaType,
+    pub media_id: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+}
+
+impl ListItem {
+    pub fn new(media_type: MediaType, media_id: u64) -> Self {
+        Self {
+            media_type,
+            media_id,
+            comment: None,
+        }
+    }
+
+    pub fn movie(media_id: u64) -> Self {
+        Self::new(MediaType::Movie, media_id)
+    }
+
+    pub fn tv(media_id: u64) -> Self {
+        Self::new(MediaType::Tv, media_id)
+    }
+
+    pub fn with_comment(mut self, value: Option<String>) -> Self {
+        self.comment = value;
+        self
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ItemResult {
+    pub media_type: MediaType,
+    pub media_id: u64,
+    pub success: bool,
+}
+
+/// Outcome of a bulk update of the items of a list, with the outcome for each item.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ItemsResult {
+    #[serde(flatten)]
+    pub status: StatusResult,
+    pub results: Vec<ItemResult>,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ItemStatus {
+    #[serde(flatten)]
+    pub status: StatusResult,
+    /// ID of the list.
+    pub id: u64,
+    pub media_type: MediaType,
+    pub media_id: u64,
+}
+
+/// Order of the items of a list.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SortBy {
+    #[default]
+    OriginalOrderAsc,
+    OriginalOrderDesc,
+    PrimaryReleaseDateAsc,
+    PrimaryReleaseDateDesc,
+    TitleAsc,
+    TitleDesc,
+    VoteAverageAsc,
+    VoteAverageDesc,
+}
+
+impl fmt::Display for SortBy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OriginalOrderAsc => write!(f, "original_order.asc"),
+            Self::OriginalOrderDesc => write!(f, "original_order.desc"),
+            Self::PrimaryReleaseDateAsc => write!(f, "primary_release_date.asc"),
+            Self::PrimaryReleaseDateDesc => write!(f, "primary_release_date.desc"),
+            Self::TitleAsc => write!(f, "title.asc"),
+            Self::TitleDesc => write!(f, "title.desc"),
+            Self::VoteAverageAsc => write!(f, "vote_average.asc"),
+            Self::VoteAverageDesc => write!(f, "vote_average.desc"),
+        }
+    }
+}
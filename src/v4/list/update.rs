@@ -0,0 +1,174 @@
+use std::borrow::Cow;
+
+use crate::client::Method;
+use crate::common::StatusResult;
+
+/// Update the details of a list. Only the provided fields are changed.
+///
+/// ```rust
+/// use tmdb_api::prelude::Command;
+/// use tmdb_api::client::{ApiVersion, Client};
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::v4::list::update::ListUpdate;
+/// use tmdb_api::v4::list::SortBy;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::builder()
+///         .with_api_version(ApiVersion::V4)
+///         .with_api_key("this-is-my-read-access-token".into())
+///         .with_access_token("this-is-the-user-access-token".into())
+///         .build()
+///         .unwrap();
+///     let cmd = ListUpdate::new(8213).with_sort_by(Some(SortBy::TitleAsc));
+///     let result = cmd.execute(&client).await;
+///     match result {
+///         Ok(res) => println!("found: {:#?}", res),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ListUpdate {
+    /// ID of the list.
+    pub list_id: u64,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub public: Option<bool>,
+    /// Default order of the items of the list.
+    pub sort_by: Option<super::SortBy>,
+}
+
+impl ListUpdate {
+    pub fn new(list_id: u64) -> Self {
+        Self {
+            list_id,
+            name: None,
+            description: None,
+            public: None,
+            sort_by: None,
+        }
+    }
+
+    pub fn with_name(mut self, value: Option<String>) -> Self {
+        self.name = value;
+        self
+    }
+
+    pub fn with_description(mut self, value: Option<String>) -> Self {
+        self.description = value;
+        self
+    }
+
+    pub fn with_public(mut self, value: Option<bool>) -> Self {
+        self.public = value;
+        self
+    }
+
+    pub fn with_sort_by(mut self, value: Option<super::SortBy>) -> Self {
+        self.sort_by = value;
+        self
+    }
+}
+
+impl crate::prelude::Command for ListUpdate {
+    type Output = StatusResult;
+
+    fn method(&self) -> Method {
+        Method::Put
+    }
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Owned(format!("/list/{}", self.list_id))
+    }
+
+    fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
+        Vec::new()
+    }
+
+    fn body(&self) -> Option<serde_json::Value> {
+        let mut body = serde_json::json!({});
+        if let Some(ref name) = self.name {
+            body["name"] = serde_json::json!(name);
+        }
+        if let Some(ref description) = self.description {
+            body["description"] = serde_json::json!(description);
+        }
+        if let Some(public) = self.public {
+            body["public"] = serde_json::json!(public);
+        }
+        if let Some(sort_by) = self.sort_by {
+            body["sort_by"] = serde_json::json!(sort_by.to_string());
+        }
+        Some(body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::{ApiVersion, Client};
+    use crate::prelude::Command;
+    use crate::v4::list::SortBy;
+
+    use super::ListUpdate;
+
+    #[tokio::test]
+    async fn it_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_version(ApiVersion::V4)
+            .with_api_key("secret".into())
+            .with_access_token("user-token".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("PUT", "/list/8213")
+            .match_header("authorization", "Bearer user-token")
+            .match_body(Matcher::Json(serde_json::json!({
+                "name": "Seen together",
+                "sort_by": "vote_average.desc",
+            })))
+            .with_status(201)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../../assets/v4-status-updated.json"))
+            .create_async()
+            .await;
+
+        let result = ListUpdate::new(8213)
+            .with_name(Some("Seen together".into()))
+            .with_sort_by(Some(SortBy::VoteAverageDesc))
+            .execute(&client)
+            .await
+            .unwrap();
+        assert!(result.success);
+        assert_eq!(result.status_code, Some(12));
+    }
+
+    #[tokio::test]
+    async fn resource_not_found() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_version(ApiVersion::V4)
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("PUT", "/list/8213")
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../../assets/resource-not-found.json"))
+            .create_async()
+            .await;
+
+        let err = ListUpdate::new(8213).execute(&client).await.unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 34);
+    }
+}
@@ -0,0 +1,141 @@
+use std::borrow::Cow;
+
+use crate::client::Method;
+
+use super::ListItem;
+
+/// Update the comments of items already in a list.
+///
+/// ```rust
+/// use tmdb_api::prelude::Command;
+/// use tmdb_api::client::{ApiVersion, Client};
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::v4::list::update_items::ListUpdateItems;
+/// use tmdb_api::v4::list::ListItem;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::builder()
+///         .with_api_version(ApiVersion::V4)
+///         .with_api_key("this-is-my-read-access-token".into())
+///         .with_access_token("this-is-the-user-access-token".into())
+///         .build()
+///         .unwrap();
+///     let cmd = ListUpdateItems::new(8213, vec![ListItem::movie(550).with_comment(Some("Rule #1".into()))]);
+///     let result = cmd.execute(&client).await;
+///     match result {
+///         Ok(res) => println!("found: {:#?}", res),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ListUpdateItems {
+    /// ID of the list.
+    pub list_id: u64,
+    pub items: Vec<ListItem>,
+}
+
+impl ListUpdateItems {
+    pub fn new(list_id: u64, items: Vec<ListItem>) -> Self {
+        Self { list_id, items }
+    }
+}
+
+impl crate::prelude::Command for ListUpdateItems {
+    type Output = super::ItemsResult;
+
+    fn method(&self) -> Method {
+        Method::Put
+    }
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Owned(format!("/list/{}/items", self.list_id))
+    }
+
+    fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
+        Vec::new()
+    }
+
+    fn body(&self) -> Option<serde_json::Value> {
+        Some(serde_json::json!({ "items": self.items }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::client::{ApiVersion, Client};
+    use crate::prelude::Command;
+    use crate::v4::list::ListItem;
+
+    use super::ListUpdateItems;
+
+    #[tokio::test]
+    async fn it_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_version(ApiVersion::V4)
+            .with_api_key("secret".into())
+            .with_access_token("user-token".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("PUT", "/list/8213/items")
+            .match_header("authorization", "Bearer user-token")
+            .match_body(Matcher::Json(serde_json::json!({
+                "items": [
+                    { "media_type": "movie", "media_id": 550, "comment": "Rule #1" },
+                    { "media_type": "tv", "media_id": 1399, "comment": "Winter is coming" },
+                ]
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../../assets/v4-list-items.json"))
+            .create_async()
+            .await;
+
+        let items = vec![
+            ListItem::movie(550).with_comment(Some("Rule #1".into())),
+            ListItem::tv(1399).with_comment(Some("Winter is coming".into())),
+        ];
+        let result = ListUpdateItems::new(8213, items)
+            .execute(&client)
+            .await
+            .unwrap();
+        assert!(result.status.success);
+        assert_eq!(result.results.len(), 2);
+        assert!(result.results[0].success);
+        assert!(!result.results[1].success);
+    }
+
+    #[tokio::test]
+    async fn resource_not_found() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_version(ApiVersion::V4)
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("PUT", "/list/8213/items")
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../../assets/resource-not-found.json"))
+            .create_async()
+            .await;
+
+        let err = ListUpdateItems::new(8213, vec![ListItem::movie(550)])
+            .execute(&client)
+            .await
+            .unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 34);
+    }
+}
@@ -0,0 +1,8 @@
+//! Commands of the v4 API.
+//!
+//! They have to be executed with a client built with [`ApiVersion::V4`](crate::client::ApiVersion),
+//! that authenticates with a bearer token instead of the `api_key` query param.
+
+pub mod account;
+pub mod auth;
+pub mod list;
@@ -1,4 +1,5 @@
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
+use std::fmt;
 
 #[cfg(feature = "commands")]
 pub mod list;
@@ -27,3 +28,73 @@ pub struct WatchProviderResult {
     pub id: u64,
     pub results: HashMap<String, LocatedWatchProvider>,
 }
+
+/// How a content is made available by a watch provider.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum WatchMonetizationType {
+    Flatrate,
+    Free,
+    Ads,
+    Rent,
+    Buy,
+}
+
+impl fmt::Display for WatchMonetizationType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Flatrate => write!(f, "flatrate"),
+            Self::Free => write!(f, "free"),
+            Self::Ads => write!(f, "ads"),
+            Self::Rent => write!(f, "rent"),
+            Self::Buy => write!(f, "buy"),
+        }
+    }
+}
+
+/// Monetization types accepted by the `with_watch_monetization_types` discover filter.
+///
+/// ```rust
+/// use tmdb_api::watch_provider::{WatchMonetizationType, WatchMonetizationTypes};
+///
+/// let value = WatchMonetizationTypes::from_iter([
+///     WatchMonetizationType::Rent,
+///     WatchMonetizationType::Flatrate,
+/// ]);
+/// assert_eq!(value.to_string(), "flatrate|rent");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WatchMonetizationTypes {
+    /// Any of the types has to be available.
+    Any(BTreeSet<WatchMonetizationType>),
+    /// Raw value, sent as is.
+    Other(String),
+}
+
+impl FromIterator<WatchMonetizationType> for WatchMonetizationTypes {
+    fn from_iter<I: IntoIterator<Item = WatchMonetizationType>>(iter: I) -> Self {
+        Self::Any(iter.into_iter().collect())
+    }
+}
+
+impl From<WatchMonetizationType> for WatchMonetizationTypes {
+    fn from(value: WatchMonetizationType) -> Self {
+        Self::from_iter([value])
+    }
+}
+
+impl fmt::Display for WatchMonetizationTypes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Any(types) => {
+                for (index, value) in types.iter().enumerate() {
+                    if index > 0 {
+                        f.write_str("|")?;
+                    }
+                    write!(f, "{value}")?;
+                }
+                Ok(())
+            }
+            Self::Other(value) => f.write_str(value),
+        }
+    }
+}